@@ -1,135 +1,872 @@
 #![cfg(feature = "chrono")]
 use chrono::prelude::*;
 
-impl From<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<FixedOffset> {
-    fn from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Self {
-        let date: crate::YmdDate = dt.date.into();
+impl TryFrom<crate::YmdDate> for NaiveDate {
+    type Error = crate::Error;
 
-        FixedOffset::east((dt.time.timezone * 60).into())
-            .ymd(date.year.into(), date.month.into(), date.day.into())
-            .and_hms_nano(
-                dt.time.local.naive.hour.into(),
-                dt.time.local.naive.minute.into(),
-                dt.time.local.naive.second.into(),
-                dt.time.local.nanosecond(),
-            )
+    #[inline]
+    fn try_from(date: crate::YmdDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(date.year, date.month.into(), date.day.into())
+            .ok_or(crate::Error::InvalidDate)
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Utc> {
+/// Week and ordinal dates go through their calendar date,
+/// failing rather than panicking if they are outside of their year.
+impl TryFrom<crate::Date> for NaiveDate {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt).with_timezone(&Utc)
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        crate::to_calendar_date(date)?.try_into()
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Local> {
+/// Fails for hour 24, which chrono cannot represent.
+impl TryFrom<crate::LocalTime<crate::HmsTime>> for NaiveTime {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt).with_timezone(&Local)
+    fn try_from(time: crate::LocalTime<crate::HmsTime>) -> Result<Self, Self::Error> {
+        // chrono represents a leap second as second 59 with
+        // a nanosecond of one second or more
+        let (second, nanosecond) = match time.naive.second {
+            60 => (59, 1_000_000_000 + time.nanosecond()),
+            second => (second.into(), time.nanosecond()),
+        };
+        NaiveTime::from_hms_nano_opt(
+            time.naive.hour.into(),
+            time.naive.minute.into(),
+            second,
+            nanosecond,
+        )
+        .ok_or(crate::Error::InvalidDate)
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<FixedOffset> {
+/// The fraction of the minute becomes seconds and nanoseconds.
+impl TryFrom<crate::LocalTime<crate::HmTime>> for NaiveTime {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc.fix())
+    fn try_from(time: crate::LocalTime<crate::HmTime>) -> Result<Self, Self::Error> {
+        crate::LocalTime::<crate::HmsTime>::from(time).try_into()
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Utc> {
+/// The fraction of the hour becomes minutes, seconds and nanoseconds.
+impl TryFrom<crate::LocalTime<crate::HTime>> for NaiveTime {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc)
+    fn try_from(time: crate::LocalTime<crate::HTime>) -> Result<Self, Self::Error> {
+        crate::LocalTime::<crate::HmsTime>::from(time).try_into()
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Local> {
+impl TryFrom<crate::ApproxLocalTime> for NaiveTime {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Self {
-        let date: crate::YmdDate = dt.date.into();
+    fn try_from(time: crate::ApproxLocalTime) -> Result<Self, Self::Error> {
+        crate::LocalTime::<crate::HmsTime>::from(time).try_into()
+    }
+}
 
-        Local
-            .from_local_datetime(
-                &NaiveDate::from_ymd(date.year.into(), date.month.into(), date.day.into())
-                    .and_hms_nano(
-                        dt.time.naive.hour.into(),
-                        dt.time.naive.minute.into(),
-                        dt.time.naive.second.into(),
-                        dt.time.nanosecond(),
-                    ),
-            )
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for NaiveDateTime {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        naive_datetime(dt.date, &dt.time)
+    }
+}
+
+#[inline]
+fn naive_datetime(
+    date: crate::Date,
+    time: &crate::LocalTime<crate::HmsTime>,
+) -> Result<NaiveDateTime, crate::Error> {
+    Ok(NaiveDate::try_from(date)?.and_time(NaiveTime::try_from(*time)?))
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<FixedOffset> {
+    type Error = crate::Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        FixedOffset::east_opt((dt.time.timezone.minutes() as i32) * 60)
+            .ok_or(crate::Error::InvalidDate)?
+            .from_local_datetime(&naive_datetime(dt.date, &dt.time.local)?)
             .single()
-            .unwrap() // Impossible to panic because of how
-                      // Local::from_local_datetime is implemented
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Utc> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<FixedOffset>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Local> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<FixedOffset>::try_from(dt)?.with_timezone(&Local))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<FixedOffset> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc.fix()))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Utc> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Local> {
+    type Error = crate::Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        // Fails for local times skipped by a transition of the system timezone
+        Local
+            .from_local_datetime(&naive_datetime(dt.date, &dt.time)?)
+            .earliest()
+            .ok_or(crate::Error::InvalidDate)
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<FixedOffset> {
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<FixedOffset> {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc.fix())
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc.fix()))
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Utc> {
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Utc> {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc)
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc))
     }
 }
 
-impl From<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Local> {
+/// Local times are assumed to be in the system timezone.
+/// Use [`into_local_assuming_timezone`](crate::DateTime::into_local_assuming_timezone)
+/// to choose the timezone explicitly.
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Local> {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Self {
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
         match dt.time {
             crate::AnyTime::Global(time) => crate::DateTime {
                 date: dt.date,
                 time,
             }
-            .into(),
+            .try_into(),
             crate::AnyTime::Local(time) => crate::DateTime {
                 date: dt.date,
                 time,
             }
-            .into(),
+            .try_into(),
         }
     }
 }
 
-impl From<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>> for DateTime<FixedOffset> {
+impl crate::DateTime<crate::Date, crate::AnyTime> {
+    /// Converts to a chrono datetime, interpreting a local time
+    /// as being `tz_offset_minutes` away from UTC.
+    /// Global times keep their own timezone.
+    pub fn into_local_assuming_timezone(
+        self,
+        tz_offset_minutes: i16,
+    ) -> Result<DateTime<FixedOffset>, crate::Error> {
+        let time = match self.time {
+            crate::AnyTime::Global(time) => time,
+            crate::AnyTime::Local(local) => crate::GlobalTime {
+                local,
+                timezone: crate::Timezone::Fixed(tz_offset_minutes),
+            },
+        };
+        crate::DateTime {
+            date: self.date,
+            time,
+        }
+        .try_into()
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>>
+    for DateTime<FixedOffset>
+{
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>) -> Self {
+    fn try_from(
+        dt: crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>,
+    ) -> Result<Self, Self::Error> {
         let date: crate::Date = dt.date.into();
         let time: crate::GlobalTime<crate::HmsTime> = dt.time.into();
-        crate::DateTime { date, time }.into()
+        crate::DateTime { date, time }.try_into()
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>> for DateTime<Utc> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(
+        dt: crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>,
+    ) -> Result<Self, Self::Error> {
+        Ok(DateTime::<FixedOffset>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime> {
+    /// Converts to a chrono datetime, with a reduced accuracy date
+    /// standing for the first or last day of its period.
+    /// A reduced accuracy time stands for its start, like in the
+    /// [`TryFrom`] conversion, which resolves the date to its start.
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let dt: DateTime<ApproxDate, ApproxGlobalTime> = "2018-W31T12Z".parse().unwrap();
+    /// let end = dt.into_chrono(Resolution::End).unwrap();
+    /// assert_eq!(end.to_rfc3339(), "2018-08-05T12:00:00+00:00");
+    /// ```
+    pub fn into_chrono(
+        self,
+        resolution: crate::Resolution,
+    ) -> Result<DateTime<FixedOffset>, crate::Error> {
+        let date = resolve_date(self.date, resolution)?;
+        let time: crate::GlobalTime<crate::HmsTime> = self.time.into();
+        crate::DateTime { date, time }.try_into()
+    }
+}
+
+impl crate::DateTime<crate::ApproxDate, crate::ApproxLocalTime> {
+    /// Converts to a chrono naive datetime, resolving
+    /// the date and time like for global times.
+    pub fn into_chrono(self, resolution: crate::Resolution) -> Result<NaiveDateTime, crate::Error> {
+        let date = resolve_date(self.date, resolution)?;
+        naive_datetime(date, &self.time.into())
+    }
+}
+
+/// The first or last day of the date's period,
+/// or an error instead of panicking if the date is not valid
+#[inline]
+fn resolve_date(
+    date: crate::ApproxDate,
+    resolution: crate::Resolution,
+) -> Result<crate::Date, crate::Error> {
+    use crate::Valid;
+
+    date.is_valid()
+        .then(|| crate::Date::YMD(date.resolve(resolution)))
+        .ok_or(crate::Error::InvalidDate)
+}
+
+impl From<NaiveDate> for crate::YmdDate {
+    #[inline]
+    fn from(date: NaiveDate) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+impl From<NaiveDate> for crate::Date {
+    #[inline]
+    fn from(date: NaiveDate) -> Self {
+        crate::Date::YMD(date.into())
+    }
+}
+
+impl From<crate::Weekday> for Weekday {
+    #[inline]
+    fn from(weekday: crate::Weekday) -> Self {
+        match weekday {
+            crate::Weekday::Monday => Weekday::Mon,
+            crate::Weekday::Tuesday => Weekday::Tue,
+            crate::Weekday::Wednesday => Weekday::Wed,
+            crate::Weekday::Thursday => Weekday::Thu,
+            crate::Weekday::Friday => Weekday::Fri,
+            crate::Weekday::Saturday => Weekday::Sat,
+            crate::Weekday::Sunday => Weekday::Sun,
+        }
+    }
+}
+
+impl From<Weekday> for crate::Weekday {
+    #[inline]
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => crate::Weekday::Monday,
+            Weekday::Tue => crate::Weekday::Tuesday,
+            Weekday::Wed => crate::Weekday::Wednesday,
+            Weekday::Thu => crate::Weekday::Thursday,
+            Weekday::Fri => crate::Weekday::Friday,
+            Weekday::Sat => crate::Weekday::Saturday,
+            Weekday::Sun => crate::Weekday::Sunday,
+        }
+    }
+}
+
+/// Leap seconds, which chrono represents with a nanosecond
+/// of one second or more, become second 60.
+impl From<NaiveTime> for crate::LocalTime<crate::HmsTime> {
+    #[inline]
+    fn from(time: NaiveTime) -> Self {
+        let (second, nanosecond) = match time.nanosecond() {
+            nanosecond @ 1_000_000_000.. => (60, nanosecond - 1_000_000_000),
+            nanosecond => (time.second() as u8, nanosecond),
+        };
+        Self {
+            naive: crate::HmsTime {
+                hour: time.hour() as u8,
+                minute: time.minute() as u8,
+                second,
+            },
+            fraction: nanosecond,
+            fraction_digits: 0,
+        }
+    }
+}
+
+impl From<NaiveDateTime> for crate::DateTime<crate::Date, crate::LocalTime> {
+    #[inline]
+    fn from(dt: NaiveDateTime) -> Self {
+        Self {
+            date: crate::Date::YMD(dt.date().into()),
+            time: dt.time().into(),
+        }
+    }
+}
+
+/// The timezone is written as `Z`.
+impl From<DateTime<Utc>> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    #[inline]
+    fn from(dt: DateTime<Utc>) -> Self {
+        let local = crate::DateTime::<crate::Date, crate::LocalTime>::from(dt.naive_utc());
+        Self {
+            date: local.date,
+            time: crate::GlobalTime {
+                local: local.time,
+                timezone: crate::Timezone::Utc,
+            },
+        }
     }
 }
 
-impl From<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>> for DateTime<Utc> {
+/// Fails for offsets that are not a whole number of minutes.
+impl TryFrom<DateTime<FixedOffset>> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    type Error = crate::Error;
+
+    fn try_from(dt: DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let offset = dt.offset().local_minus_utc();
+        if offset % 60 != 0 {
+            return Err(crate::Error::InvalidDate);
+        }
+
+        let local = crate::DateTime::<crate::Date, crate::LocalTime>::from(dt.naive_local());
+        Ok(Self {
+            date: local.date,
+            time: crate::GlobalTime {
+                local: local.time,
+                timezone: crate::Timezone::Fixed((offset / 60) as i16),
+            },
+        })
+    }
+}
+
+/// Fails for offsets that are not a whole number of minutes.
+impl TryFrom<DateTime<Local>> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    type Error = crate::Error;
+
     #[inline]
-    fn from(dt: crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt).with_timezone(&Utc)
+    fn try_from(dt: DateTime<Local>) -> Result<Self, Self::Error> {
+        dt.fixed_offset().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive_date() {
+        assert_eq!(
+            NaiveDate::try_from(crate::YmdDate {
+                year: 2016,
+                month: 2,
+                day: 29,
+            })
+            .unwrap(),
+            NaiveDate::from_ymd_opt(2016, 2, 29).unwrap()
+        );
+        assert!(NaiveDate::try_from(crate::YmdDate {
+            year: 2018,
+            month: 13,
+            day: 1,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn global() {
+        let datetime: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2018-08-02T13:42:00.5+02:00".parse().unwrap();
+        assert_eq!(
+            DateTime::<FixedOffset>::try_from(datetime)
+                .unwrap()
+                .to_rfc3339(),
+            "2018-08-02T13:42:00.500+02:00"
+        );
+        assert_eq!(
+            DateTime::<Utc>::try_from(datetime).unwrap().to_rfc3339(),
+            "2018-08-02T11:42:00.500+00:00"
+        );
+
+        let invalid = crate::DateTime {
+            date: crate::Date::YMD(crate::YmdDate {
+                year: 2018,
+                month: 2,
+                day: 30,
+            }),
+            ..datetime
+        };
+        assert!(DateTime::<Utc>::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn into_naive() {
+        let expected = NaiveDate::from_ymd_opt(2018, 1, 9).unwrap();
+        for s in [
+            "2018-01-09",
+            "20180109",
+            "2018-W02-2",
+            "2018W022",
+            "2018-009",
+            "2018009",
+        ] {
+            let date: crate::Date = s.parse().unwrap();
+            assert_eq!(NaiveDate::try_from(date).unwrap(), expected, "{}", s);
+        }
+        assert!(NaiveDate::try_from(crate::Date::YMD(crate::YmdDate {
+            year: 2018,
+            month: 2,
+            day: 30,
+        }))
+        .is_err());
+
+        let time: crate::LocalTime = "13:42:05.25".parse().unwrap();
+        assert_eq!(
+            NaiveTime::try_from(time).unwrap(),
+            NaiveTime::from_hms_milli_opt(13, 42, 5, 250).unwrap()
+        );
+        let leap_second: crate::LocalTime = "23:59:60.5".parse().unwrap();
+        assert_eq!(
+            NaiveTime::try_from(leap_second).unwrap(),
+            NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap()
+        );
+        let midnight: crate::LocalTime = "24:00:00".parse().unwrap();
+        assert!(NaiveTime::try_from(midnight).is_err());
+
+        let hm: crate::LocalTime<crate::HmTime> = "13:42.5".parse().unwrap();
+        assert_eq!(
+            NaiveTime::try_from(hm).unwrap(),
+            NaiveTime::from_hms_opt(13, 42, 30).unwrap()
+        );
+        let h: crate::LocalTime<crate::HTime> = "13,25".parse().unwrap();
+        assert_eq!(
+            NaiveTime::try_from(h).unwrap(),
+            NaiveTime::from_hms_opt(13, 15, 0).unwrap()
+        );
+        let midnight: crate::LocalTime<crate::HTime> = "24".parse().unwrap();
+        assert!(NaiveTime::try_from(midnight).is_err());
+        for s in ["13:42:05.25", "1342", "13"] {
+            let approx: crate::ApproxLocalTime = s.parse().unwrap();
+            assert_eq!(
+                NaiveTime::try_from(approx).unwrap(),
+                NaiveTime::try_from(crate::LocalTime::<crate::HmsTime>::from(approx)).unwrap(),
+                "{}",
+                s
+            );
+        }
+
+        let datetime: crate::DateTime<crate::Date, crate::LocalTime> =
+            "2018-W02-2T13:42:05.25".parse().unwrap();
+        assert_eq!(
+            NaiveDateTime::try_from(datetime).unwrap(),
+            expected.and_hms_milli_opt(13, 42, 5, 250).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolution() {
+        let global = |s: &str| {
+            s.parse::<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>>()
+                .unwrap()
+        };
+        let fixed = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+
+        for (s, start, end) in [
+            (
+                "2016-02T12Z",
+                "2016-02-01T12:00:00Z",
+                "2016-02-29T12:00:00Z",
+            ),
+            (
+                "2018T08:30+02:00",
+                "2018-01-01T08:30:00+02:00",
+                "2018-12-31T08:30:00+02:00",
+            ),
+            (
+                "2018-W01T00Z",
+                "2018-01-01T00:00:00Z",
+                "2018-01-07T00:00:00Z",
+            ),
+            (
+                "2018-08-02T13:42Z",
+                "2018-08-02T13:42:00Z",
+                "2018-08-02T13:42:00Z",
+            ),
+        ] {
+            let dt = global(s);
+            assert_eq!(
+                dt.into_chrono(crate::Resolution::Start).unwrap(),
+                fixed(start)
+            );
+            assert_eq!(dt.into_chrono(crate::Resolution::End).unwrap(), fixed(end));
+            assert_eq!(DateTime::<FixedOffset>::try_from(dt).unwrap(), fixed(start));
+        }
+
+        let local: crate::DateTime<crate::ApproxDate, crate::ApproxLocalTime> =
+            "2018-08T13:42,5".parse().unwrap();
+        assert_eq!(
+            local.into_chrono(crate::Resolution::End).unwrap(),
+            NaiveDate::from_ymd_opt(2018, 8, 31)
+                .unwrap()
+                .and_hms_opt(13, 42, 30)
+                .unwrap()
+        );
+
+        // invalid dates are errors rather than panics
+        for date in [
+            crate::ApproxDate::W(crate::WDate {
+                year: 2018,
+                week: 53,
+            }),
+            crate::ApproxDate::O(crate::ODate {
+                year: 2018,
+                day: 366,
+            }),
+            crate::ApproxDate::YM(crate::YmDate {
+                year: 2018,
+                month: 13,
+            }),
+        ] {
+            let dt = crate::DateTime {
+                date,
+                ..global("2018T12Z")
+            };
+            assert!(dt.into_chrono(crate::Resolution::Start).is_err());
+            assert!(dt.into_chrono(crate::Resolution::End).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_components() {
+        let time: crate::GlobalTime = "12:00:00Z".parse().unwrap();
+        for date in [
+            crate::Date::YMD(crate::YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1,
+            }),
+            crate::Date::WD(crate::WdDate {
+                year: 2018,
+                week: 53,
+                day: 1,
+            }),
+            crate::Date::WD(crate::WdDate {
+                year: 2018,
+                week: 1,
+                day: 9,
+            }),
+            crate::Date::O(crate::ODate {
+                year: 2018,
+                day: 366,
+            }),
+            crate::Date::O(crate::ODate {
+                year: 2018,
+                day: 1_000,
+            }),
+        ] {
+            assert!(NaiveDate::try_from(date).is_err(), "{:?}", date);
+
+            let global = crate::DateTime { date, time };
+            assert!(DateTime::<FixedOffset>::try_from(global).is_err());
+            assert!(DateTime::<Utc>::try_from(global).is_err());
+            assert!(DateTime::<Local>::try_from(global).is_err());
+
+            let local = crate::DateTime {
+                date,
+                time: time.local,
+            };
+            assert!(NaiveDateTime::try_from(local).is_err());
+            assert!(DateTime::<Local>::try_from(local).is_err());
+
+            let approx = crate::DateTime {
+                date: crate::ApproxDate::from(date),
+                time: crate::ApproxGlobalTime::HMS(time),
+            };
+            assert!(DateTime::<Utc>::try_from(approx).is_err());
+        }
+
+        let mut global: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2018-08-02T12:00:00Z".parse().unwrap();
+        global.time.local.naive.minute = 60;
+        assert!(DateTime::<Utc>::try_from(global).is_err());
+        global.time.local.naive.minute = 0;
+        global.time.timezone = crate::Timezone::Fixed(24 * 60);
+        assert!(DateTime::<Utc>::try_from(global).is_err());
+    }
+
+    #[test]
+    fn leap_second() {
+        let datetime: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2016-12-31T23:59:60Z".parse().unwrap();
+        let utc = DateTime::<Utc>::try_from(datetime).unwrap();
+        assert_eq!(utc.second(), 59);
+        assert_eq!(utc.nanosecond(), 1_000_000_000);
+        assert_eq!(
+            crate::DateTime::from(utc).to_string(),
+            "2016-12-31T23:59:60Z"
+        );
+
+        let datetime: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2016-12-31T23:59:60.25Z".parse().unwrap();
+        let utc = DateTime::<Utc>::try_from(datetime).unwrap();
+        assert_eq!(utc.nanosecond(), 1_250_000_000);
+    }
+
+    #[test]
+    fn from_naive() {
+        let date = NaiveDate::from_ymd_opt(2016, 2, 29).unwrap();
+        assert_eq!(
+            crate::YmdDate::from(date),
+            crate::YmdDate {
+                year: 2016,
+                month: 2,
+                day: 29,
+            }
+        );
+
+        let time = NaiveTime::from_hms_nano_opt(13, 42, 5, 123_456_789).unwrap();
+        assert_eq!(
+            crate::LocalTime::from(time).to_string(),
+            "13:42:05.123456789"
+        );
+        let leap_second = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert_eq!(
+            crate::LocalTime::from(leap_second).to_string(),
+            "23:59:60.5"
+        );
+
+        assert_eq!(
+            crate::DateTime::from(date.and_time(time)).to_string(),
+            "2016-02-29T13:42:05.123456789"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut date = NaiveDate::from_ymd_opt(2020, 12, 20).unwrap();
+        for _ in 0..20 {
+            let converted = crate::Date::from(date);
+            let parsed: crate::Date = converted.to_string().parse().unwrap();
+            assert_eq!(NaiveDate::try_from(parsed).unwrap(), date);
+
+            let week = crate::Date::WD(crate::to_week_date(converted).unwrap());
+            assert_eq!(NaiveDate::try_from(week).unwrap(), date);
+            assert_eq!(week.to_string(), date.format("%G-W%V-%u").to_string());
+
+            assert_eq!(crate::Weekday::from(date.weekday()), converted.weekday());
+            assert_eq!(Weekday::from(converted.weekday()), date.weekday());
+            date = date.succ_opt().unwrap();
+        }
+
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_250_000_000).unwrap();
+        let parsed: crate::LocalTime = crate::LocalTime::from(time).to_string().parse().unwrap();
+        assert_eq!(NaiveTime::try_from(parsed).unwrap(), time);
+    }
+
+    #[test]
+    fn from_global() {
+        let utc = Utc.with_ymd_and_hms(2018, 8, 2, 11, 42, 0).unwrap();
+        assert_eq!(
+            crate::DateTime::from(utc).to_string(),
+            "2018-08-02T11:42:00Z"
+        );
+
+        let fixed = DateTime::parse_from_rfc3339("2018-08-02T13:42:00.5+02:00").unwrap();
+        let datetime = crate::DateTime::try_from(fixed).unwrap();
+        assert_eq!(datetime.to_string(), "2018-08-02T13:42:00.5+02:00");
+        assert_eq!(DateTime::<FixedOffset>::try_from(datetime).unwrap(), fixed);
+
+        let zero = DateTime::parse_from_rfc3339("2018-08-02T11:42:00+00:00").unwrap();
+        assert_eq!(
+            crate::DateTime::try_from(zero).unwrap().to_string(),
+            "2018-08-02T11:42:00+00:00"
+        );
+
+        let seconds = FixedOffset::east_opt(30)
+            .unwrap()
+            .with_ymd_and_hms(2018, 8, 2, 11, 42, 0)
+            .unwrap();
+        assert!(matches!(
+            crate::DateTime::try_from(seconds),
+            Err(crate::Error::InvalidDate)
+        ));
+
+        let local = Local.with_ymd_and_hms(2018, 8, 2, 11, 42, 0).unwrap();
+        let datetime = crate::DateTime::try_from(local).unwrap();
+        assert_eq!(DateTime::<Local>::try_from(datetime).unwrap(), local);
+    }
+
+    #[test]
+    fn into_local_assuming_timezone() {
+        let datetime: crate::DateTime<crate::Date, crate::AnyTime> =
+            "2018-08-02T13:42:00".parse().unwrap();
+        assert_eq!(
+            datetime
+                .into_local_assuming_timezone(120)
+                .unwrap()
+                .to_rfc3339(),
+            "2018-08-02T13:42:00+02:00"
+        );
+
+        let datetime: crate::DateTime<crate::Date, crate::AnyTime> =
+            "2018-08-02T13:42:00-05:00".parse().unwrap();
+        assert_eq!(
+            datetime
+                .into_local_assuming_timezone(120)
+                .unwrap()
+                .to_rfc3339(),
+            "2018-08-02T13:42:00-05:00"
+        );
     }
 }
 
 #[cfg(feature = "chrono-serde")]
 pub mod serde {
     use super::{DateTime, TimeZone};
-    use serde::{Deserialize, Deserializer};
+    use serde::{Deserialize, Deserializer, Serializer};
 
+    /// Counterpart of [`deserialize_DateTime`], writing the datetime
+    /// in the extended format with its offset.
+    #[allow(non_snake_case)]
+    pub fn serialize_DateTime<S, Tz>(dt: &DateTime<Tz>, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Tz: TimeZone,
+        crate::DateTime<crate::Date, crate::GlobalTime>: TryFrom<DateTime<Tz>>,
+        <crate::DateTime<crate::Date, crate::GlobalTime> as TryFrom<DateTime<Tz>>>::Error:
+            core::fmt::Display,
+    {
+        let dt = crate::DateTime::<crate::Date, crate::GlobalTime>::try_from(dt.clone())
+            .map_err(serde::ser::Error::custom)?;
+        ser.collect_str(&dt)
+    }
+
+    /// Parses an ISO-8601 datetime with a timezone, which may have reduced accuracy.
     #[allow(non_snake_case)]
     pub fn deserialize_DateTime<'de, D, Tz>(de: D) -> Result<DateTime<Tz>, D::Error>
     where
         D: Deserializer<'de>,
         Tz: TimeZone,
-        DateTime<Tz>: From<crate::DateTime<crate::ApproxDate, crate::ApproxAnyTime>>,
+        DateTime<Tz>: TryFrom<
+            crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>,
+            Error = crate::Error,
+        >,
     {
-        Ok(
-            crate::parse::datetime_approx_any_approx(String::deserialize(de)?.as_bytes())
-                .map_err(serde::de::Error::custom)?
-                .1
-                .into(),
-        )
+        String::deserialize(de)?
+            .parse::<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>>()
+            .map_err(serde::de::Error::custom)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "chrono-serde"))]
+mod serde_tests {
+    use chrono::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Event {
+        #[serde(
+            serialize_with = "super::serde::serialize_DateTime",
+            deserialize_with = "super::serde::deserialize_DateTime"
+        )]
+        utc: DateTime<Utc>,
+        #[serde(
+            serialize_with = "super::serde::serialize_DateTime",
+            deserialize_with = "super::serde::deserialize_DateTime"
+        )]
+        fixed: DateTime<FixedOffset>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"utc":"2018-08-02T11:42:00.5Z","fixed":"2018-08-02T13:42:00+02:00"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(event.utc.to_rfc3339(), "2018-08-02T11:42:00.500+00:00");
+        assert_eq!(event.fixed.to_rfc3339(), "2018-08-02T13:42:00+02:00");
+        assert_eq!(serde_json::to_string(&event).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Event>(&serde_json::to_string(&event).unwrap()).unwrap(),
+            event
+        );
+
+        let seconds = Event {
+            fixed: FixedOffset::east_opt(30)
+                .unwrap()
+                .with_ymd_and_hms(2018, 8, 2, 11, 42, 0)
+                .unwrap(),
+            ..event
+        };
+        assert!(serde_json::to_string(&seconds).is_err());
+
+        let reduced: Event =
+            serde_json::from_str(r#"{"utc":"2018-W31-4T11Z","fixed":"20180802T13+02"}"#).unwrap();
+        assert_eq!(
+            reduced.utc,
+            Utc.with_ymd_and_hms(2018, 8, 2, 11, 0, 0).unwrap()
+        );
+        assert!(serde_json::from_str::<Event>(r#"{"utc":"2018-08-02T11:42","fixed":""}"#).is_err());
     }
 }
@@ -0,0 +1,148 @@
+#![cfg(feature = "clock")]
+use {
+    crate::{DateTime, GlobalTime, HmsTime, LocalTime, Timezone, YmdDate},
+    std::time::SystemTime,
+};
+
+/// Offset of local time from UTC in minutes.
+/// Without the `system-timezone` feature, local time is UTC.
+#[inline]
+fn local_offset_minutes() -> i16 {
+    #[cfg(feature = "system-timezone")]
+    return system_utc_offset_minutes();
+    #[cfg(not(feature = "system-timezone"))]
+    return 0;
+}
+
+/// Current offset of the system timezone from UTC in minutes,
+/// or `0` if it cannot be determined.
+///
+/// This is not DST-aware on Windows,
+/// where the standard offset is returned even during daylight saving time.
+#[cfg(feature = "system-timezone")]
+pub fn system_utc_offset_minutes() -> i16 {
+    #[cfg(unix)]
+    {
+        let mut tm = std::mem::MaybeUninit::<libc::tm>::zeroed();
+        // SAFETY: `localtime_r` is the thread safe variant
+        // and only writes to the provided `tm`.
+        let tm = unsafe {
+            let now = libc::time(std::ptr::null_mut());
+            if libc::localtime_r(&now, tm.as_mut_ptr()).is_null() {
+                return 0;
+            }
+            tm.assume_init()
+        };
+        (tm.tm_gmtoff / 60) as i16
+    }
+
+    #[cfg(windows)]
+    {
+        use winapi::um::timezoneapi::{
+            GetTimeZoneInformation, TIME_ZONE_ID_INVALID, TIME_ZONE_INFORMATION,
+        };
+
+        // SAFETY: `TIME_ZONE_INFORMATION` is plain old data
+        // and `GetTimeZoneInformation` only writes to it.
+        let info = unsafe {
+            let mut info: TIME_ZONE_INFORMATION = std::mem::zeroed();
+            if GetTimeZoneInformation(&mut info) == TIME_ZONE_ID_INVALID {
+                return 0;
+            }
+            info
+        };
+        // The bias is UTC minus local time
+        -(info.Bias as i16)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    0
+}
+
+/// Current time with the given offset from UTC
+fn now(timezone: Timezone) -> DateTime<YmdDate, GlobalTime<HmsTime>> {
+    let utc = DateTime::try_from(SystemTime::now()).expect("system clock out of range");
+
+    let mut datetime =
+        DateTime::from_unix_seconds(utc.timestamp() + timezone.minutes() as i64 * 60)
+            .expect("system clock out of range");
+    datetime.time.local.fraction = utc.time.local.fraction;
+    datetime.time.timezone = timezone;
+    datetime
+}
+
+/// Current date and time in UTC
+#[inline]
+pub fn now_utc() -> DateTime<YmdDate, GlobalTime<HmsTime>> {
+    now(Timezone::Utc)
+}
+
+/// Current date in UTC
+#[inline]
+pub fn today_utc() -> YmdDate {
+    now_utc().date
+}
+
+/// Current local date and time
+#[inline]
+pub fn now_local() -> DateTime<YmdDate, LocalTime<HmsTime>> {
+    let now = now(Timezone::Fixed(local_offset_minutes()));
+    DateTime {
+        date: now.date,
+        time: now.time.local,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::Valid, std::time::UNIX_EPOCH};
+
+    fn system_seconds() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    #[test]
+    fn now_utc() {
+        let before = system_seconds();
+        let now = super::now_utc();
+        let after = system_seconds();
+
+        assert!(now.is_valid());
+        assert_eq!(now.time.timezone, Timezone::Utc);
+        assert!((before..=after).contains(&now.unix_seconds()));
+        assert!(now.date.year >= 2024);
+    }
+
+    #[test]
+    fn today_utc() {
+        let today = super::today_utc();
+        let now = super::now_utc();
+        // Allow for the date changing between both calls
+        assert!(today == now.date || now.time.local.naive.hour == 0);
+    }
+
+    #[test]
+    #[cfg(feature = "system-timezone")]
+    fn system_utc_offset_minutes() {
+        let offset = super::system_utc_offset_minutes();
+        assert!((-23 * 60..=23 * 60).contains(&offset));
+    }
+
+    #[test]
+    fn now_local() {
+        let now = super::now_local();
+        assert!(now.is_valid());
+
+        let local = DateTime {
+            date: now.date,
+            time: GlobalTime {
+                local: now.time,
+                timezone: Timezone::Fixed(local_offset_minutes()),
+            },
+        };
+        assert!((local.unix_seconds() - system_seconds()).abs() <= 2);
+    }
+}
@@ -1,16 +1,19 @@
-use {crate::Valid, std::convert::From};
+use {
+    crate::{Valid, WriteIso8601},
+    core::convert::From,
+};
 
 /// Complete date representations
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub enum Date<Y: Year = i16> {
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum Date<Y: Year = i32> {
     YMD(YmdDate<Y>),
     WD(WdDate<Y>),
     O(ODate<Y>),
 }
 
 /// Date representations with reduced accuracy
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub enum ApproxDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum ApproxDate<Y: Year = i32> {
     YMD(YmdDate<Y>),
     YM(YmDate<Y>),
     Y(YDate<Y>),
@@ -21,66 +24,343 @@ pub enum ApproxDate<Y: Year = i16> {
 }
 
 /// Calendar date (4.1.2.2)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct YmdDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct YmdDate<Y: Year = i32> {
     pub year: Y,
     pub month: u8,
     pub day: u8,
 }
 
 /// A specific month (4.1.2.3a)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct YmDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct YmDate<Y: Year = i32> {
     pub year: Y,
     pub month: u8,
 }
 
 /// A specific year (4.1.2.3b)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct YDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct YDate<Y: Year = i32> {
     pub year: Y,
 }
 
 // TODO support expanded century
 /// A specific century (4.1.2.3c)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct CDate {
     pub century: i8,
 }
 
 /// Week date (4.1.4.2)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct WdDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct WdDate<Y: Year = i32> {
     pub year: Y,
     pub week: u8,
     pub day: u8,
 }
 
 /// A specific week (4.1.4.3)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct WDate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct WDate<Y: Year = i32> {
     pub year: Y,
     pub week: u8,
 }
 
 /// Ordinal date (4.1.3)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct ODate<Y: Year = i16> {
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct ODate<Y: Year = i32> {
     pub year: Y,
     pub day: u16,
 }
 
-pub trait Datelike<Y: Year = i16> {}
+/// Day of the week, numbered from Monday to Sunday (4.1.4.2)
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+#[repr(u8)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+}
+
+impl Weekday {
+    /// Ordinal number of the day in the week, from 1 for Monday to 7 for Sunday
+    #[inline]
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// Day of the week of the given number of days since 1970-01-01, a Thursday
+    #[inline]
+    fn from_days_since_epoch(days: i64) -> Self {
+        match (days + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+impl From<Weekday> for u8 {
+    #[inline]
+    fn from(weekday: Weekday) -> Self {
+        weekday.number()
+    }
+}
+
+impl TryFrom<u8> for Weekday {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1..=7 => Ok(Self::from_days_since_epoch(number as i64 - 4)),
+            _ => Err(crate::Error::InvalidDate),
+        }
+    }
+}
+
+/// Access to the calendar components of a date.
+/// Components that the representation is not precise enough for are `None`,
+/// as are the ones computed from another representation of an invalid date.
+pub trait Datelike {
+    fn year(&self) -> i32;
+    fn month(&self) -> Option<u8>;
+    fn day(&self) -> Option<u8>;
+
+    /// Day of the year, from 1 to 366
+    #[inline]
+    fn ordinal(&self) -> Option<u16> {
+        let (month, day) = (self.month()?, self.day()?);
+        let date = YmdDate {
+            year: self.year(),
+            month,
+            day,
+        };
+        date.is_valid().then(|| ODate::from(date).day)
+    }
+
+    /// Week of the week-numbering year, which may differ
+    /// from the calendar year around January 1st
+    #[inline]
+    fn iso_week(&self) -> Option<WDate> {
+        let (month, day) = (self.month()?, self.day()?);
+        let date = YmdDate {
+            year: self.year(),
+            month,
+            day,
+        };
+        date.is_valid().then(|| WdDate::from(date).into())
+    }
+}
+
+/// Calendar date of a date in another representation, or `None` if it is invalid
+#[inline]
+fn valid_ymd<D: Valid + Copy>(date: &D) -> Option<YmdDate>
+where
+    YmdDate: From<D>,
+{
+    date.is_valid().then(|| YmdDate::from(*date))
+}
+
+impl Datelike for YmdDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        Some(self.month)
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        Some(self.day)
+    }
+}
+
+impl Datelike for YmDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        Some(self.month)
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        None
+    }
+}
+
+impl Datelike for YDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        None
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        None
+    }
+}
+
+/// The year is the first year of the century.
+impl Datelike for CDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.century as i32 * 100
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        None
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        None
+    }
+}
+
+/// The year is the calendar year, and the week-numbering year
+/// is given by [`iso_week`](Datelike::iso_week).
+impl Datelike for WdDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        valid_ymd(self).map_or(self.year, |date| date.year)
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        Some(valid_ymd(self)?.month)
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        Some(valid_ymd(self)?.day)
+    }
+
+    #[inline]
+    fn iso_week(&self) -> Option<WDate> {
+        self.is_valid().then(|| (*self).into())
+    }
+}
+
+/// A week may span two months, so neither month nor day is known.
+/// The year is the week-numbering year.
+impl Datelike for WDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        None
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        None
+    }
+
+    #[inline]
+    fn iso_week(&self) -> Option<WDate> {
+        self.is_valid().then_some(*self)
+    }
+}
+
+impl Datelike for ODate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        Some(valid_ymd(self)?.month)
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        Some(valid_ymd(self)?.day)
+    }
+
+    #[inline]
+    fn ordinal(&self) -> Option<u16> {
+        self.is_valid().then_some(self.day)
+    }
+}
+
+macro_rules! impl_datelike_delegate {
+    ($ty:ty, $($variant:path),+) => {
+        impl Datelike for $ty {
+            #[inline]
+            fn year(&self) -> i32 {
+                match self {
+                    $($variant(date) => date.year(),)+
+                }
+            }
+
+            #[inline]
+            fn month(&self) -> Option<u8> {
+                match self {
+                    $($variant(date) => date.month(),)+
+                }
+            }
+
+            #[inline]
+            fn day(&self) -> Option<u8> {
+                match self {
+                    $($variant(date) => date.day(),)+
+                }
+            }
+
+            #[inline]
+            fn ordinal(&self) -> Option<u16> {
+                match self {
+                    $($variant(date) => date.ordinal(),)+
+                }
+            }
+
+            #[inline]
+            fn iso_week(&self) -> Option<WDate> {
+                match self {
+                    $($variant(date) => date.iso_week(),)+
+                }
+            }
+        }
+    };
+}
 
-impl<Y: Year> Datelike<Y> for Date<Y> {}
-impl<Y: Year> Datelike<Y> for ApproxDate<Y> {}
-impl<Y: Year> Datelike<Y> for YmdDate<Y> {}
-impl<Y: Year> Datelike<Y> for YmDate<Y> {}
-impl<Y: Year> Datelike<Y> for YDate<Y> {}
-impl<Y: Year> Datelike<Y> for CDate {}
-impl<Y: Year> Datelike<Y> for WdDate<Y> {}
-impl<Y: Year> Datelike<Y> for WDate<Y> {}
-impl<Y: Year> Datelike<Y> for ODate<Y> {}
+impl_datelike_delegate!(Date, Date::YMD, Date::WD, Date::O);
+impl_datelike_delegate!(
+    ApproxDate,
+    ApproxDate::YMD,
+    ApproxDate::YM,
+    ApproxDate::Y,
+    ApproxDate::C,
+    ApproxDate::WD,
+    ApproxDate::W,
+    ApproxDate::O
+);
 
 impl_fromstr_parse!(Date, date);
 impl_fromstr_parse!(ApproxDate, date_approx);
@@ -92,6 +372,125 @@ impl_fromstr_parse!(WdDate, date_wd);
 impl_fromstr_parse!(WDate, date_w);
 impl_fromstr_parse!(ODate, date_o);
 
+impl_parse_strict!(Date, date_strict);
+
+impl_parse_expanded!(Date, date_expanded);
+impl_parse_expanded!(YmdDate, date_ymd_expanded);
+impl_parse_expanded!(WdDate, date_wd_expanded);
+impl_parse_expanded!(ODate, date_o_expanded);
+
+/// Writes a year with at least four digits,
+/// using the expanded representation's sign outside of 0 to 9999.
+#[inline]
+fn write_year<W: core::fmt::Write>(w: &mut W, year: i32) -> core::fmt::Result {
+    if (0..=9999).contains(&year) {
+        write!(w, "{:04}", year)
+    } else {
+        write!(w, "{:+05}", year)
+    }
+}
+
+impl WriteIso8601 for YmdDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        write_year(w, self.year)?;
+        if extended {
+            write!(w, "-{:02}-{:02}", self.month, self.day)
+        } else {
+            write!(w, "{:02}{:02}", self.month, self.day)
+        }
+    }
+}
+
+/// The basic format has no year and month representation without
+/// the separator (4.1.2.3a), so both formats write `YYYY-MM`.
+impl WriteIso8601 for YmDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, _: bool) -> core::fmt::Result {
+        write_year(w, self.year)?;
+        write!(w, "-{:02}", self.month)
+    }
+}
+
+impl WriteIso8601 for YDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, _: bool) -> core::fmt::Result {
+        write_year(w, self.year)
+    }
+}
+
+impl WriteIso8601 for CDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, _: bool) -> core::fmt::Result {
+        if self.century < 0 {
+            write!(w, "{:+03}", self.century)
+        } else {
+            write!(w, "{:02}", self.century)
+        }
+    }
+}
+
+impl WriteIso8601 for WdDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        write_year(w, self.year)?;
+        if extended {
+            write!(w, "-W{:02}-{}", self.week, self.day)
+        } else {
+            write!(w, "W{:02}{}", self.week, self.day)
+        }
+    }
+}
+
+impl WriteIso8601 for WDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        write_year(w, self.year)?;
+        if extended {
+            w.write_char('-')?;
+        }
+        write!(w, "W{:02}", self.week)
+    }
+}
+
+impl WriteIso8601 for ODate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        write_year(w, self.year)?;
+        if extended {
+            w.write_char('-')?;
+        }
+        write!(w, "{:03}", self.day)
+    }
+}
+
+impl WriteIso8601 for Date {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match self {
+            Date::YMD(date) => date.write_iso8601(w, extended),
+            Date::WD(date) => date.write_iso8601(w, extended),
+            Date::O(date) => date.write_iso8601(w, extended),
+        }
+    }
+}
+
+impl WriteIso8601 for ApproxDate {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match self {
+            ApproxDate::YMD(date) => date.write_iso8601(w, extended),
+            ApproxDate::YM(date) => date.write_iso8601(w, extended),
+            ApproxDate::Y(date) => date.write_iso8601(w, extended),
+            ApproxDate::C(date) => date.write_iso8601(w, extended),
+            ApproxDate::WD(date) => date.write_iso8601(w, extended),
+            ApproxDate::W(date) => date.write_iso8601(w, extended),
+            ApproxDate::O(date) => date.write_iso8601(w, extended),
+        }
+    }
+}
+
+impl_display_iso8601!(YmdDate);
+impl_display_iso8601!(YmDate);
+impl_display_iso8601!(YDate);
+impl_display_iso8601!(CDate);
+impl_display_iso8601!(WdDate);
+impl_display_iso8601!(WDate);
+impl_display_iso8601!(ODate);
+impl_display_iso8601!(Date);
+impl_display_iso8601!(ApproxDate);
+
 impl<Y> Valid for Date<Y>
 where
     Y: Year + Clone,
@@ -130,20 +529,49 @@ where
 {
     #[inline]
     fn is_valid(&self) -> bool {
-        self.day >= 1
-            && self.day
-                <= match self.month {
-                    1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-                    4 | 6 | 9 | 11 => 30,
-                    2 => {
-                        if self.year.is_leap() {
-                            29
-                        } else {
-                            28
-                        }
-                    }
-                    _ => return false,
-                }
+        matches!(self.days_in_month(), Some(days) if self.day >= 1 && self.day <= days)
+    }
+}
+
+impl<Y: Year> YmdDate<Y> {
+    /// Builds a calendar date, checking that the day exists.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(year: Y, month: u8, day: u8) -> Result<Self, crate::Error> {
+        let date = Self { year, month, day };
+        date.is_valid()
+            .then_some(date)
+            .ok_or(crate::Error::InvalidDate)
+    }
+
+    /// Number of days in the month, or `None` if the month does not exist
+    #[inline]
+    pub(crate) fn days_in_month(&self) -> Option<u8> {
+        month_days(self.year.is_leap(), self.month)
+    }
+}
+
+/// Whether `year` is a leap year in the proleptic Gregorian calendar
+#[inline]
+pub fn is_leap_year<Y: Year>(year: Y) -> bool {
+    year.is_leap()
+}
+
+/// Number of days in `month` of `year` in the proleptic Gregorian calendar,
+/// or `None` if the month does not exist
+#[inline]
+pub fn days_in_month<Y: Year>(year: Y, month: u8) -> Option<u8> {
+    month_days(year.is_leap(), month)
+}
+
+#[inline]
+fn month_days(leap: bool, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if leap => Some(29),
+        2 => Some(28),
+        _ => None,
     }
 }
 
@@ -204,6 +632,55 @@ where
     }
 }
 
+impl<Y: Year> YmDate<Y> {
+    /// Builds a year and month, checking that the month exists.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(year: Y, month: u8) -> Result<Self, crate::Error> {
+        let date = Self { year, month };
+        date.is_valid()
+            .then_some(date)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl<Y: Year + Clone> WdDate<Y> {
+    /// Builds a week date, checking that the week exists in
+    /// the year and that the weekday is between 1 and 7.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(year: Y, week: u8, day: u8) -> Result<Self, crate::Error> {
+        let date = Self { year, week, day };
+        date.is_valid()
+            .then_some(date)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl<Y: Year> WDate<Y> {
+    /// Builds a year and week, checking that the week exists in the year.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(year: Y, week: u8) -> Result<Self, crate::Error> {
+        let date = Self { year, week };
+        date.is_valid()
+            .then_some(date)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl<Y: Year> ODate<Y> {
+    /// Builds an ordinal date, checking that the day exists in the year.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(year: Y, day: u16) -> Result<Self, crate::Error> {
+        let date = Self { year, day };
+        date.is_valid()
+            .then_some(date)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
 pub trait Year {
     fn is_leap(&self) -> bool;
     fn num_weeks(&self) -> u8;
@@ -245,8 +722,14 @@ macro_rules! impl_year {
             #[inline]
             fn num_weeks(&self) -> u8 {
                 // https://en.wikipedia.org/wiki/ISO_week_date#Weeks_per_year
-                let p = |x| (x + x / 4 - x / 100 + x / 400) % 7;
-                if p(*self) == 4 || p(self - 1) == 3 {
+                // p(y - 1) is p(y) minus one, or minus two in a leap year, so
+                // p(y - 1) == 3 needs no previous year, which could overflow,
+                // and each term is reduced first so that the sum cannot either
+                let r = |x: $ty| x.rem_euclid(7);
+                let p = (r(*self) + r(self.div_euclid(4)) + 7 - r(self.div_euclid(100))
+                    + r(self.div_euclid(400)))
+                    % 7;
+                if p == 4 || (p == 5 && self.is_leap()) {
                     53
                 } else {
                     52
@@ -317,6 +800,56 @@ where
     }
 }
 
+/// Orders by the day the dates denote, then by representation,
+/// calendar before week before ordinal, so that only identical
+/// dates compare equal.
+///
+/// Calendar dates with a month out of range have no ordinal day,
+/// and come after the days of their year, by month and day.
+impl<Y> Ord for Date<Y>
+where
+    Y: Year + Ord + Copy,
+    ODate<Y>: From<Date<Y>>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let rank = |d: &Self| match d {
+            Self::YMD(_) => 0,
+            Self::WD(_) => 1,
+            Self::O(_) => 2,
+        };
+        let day = |d: &Self| match *d {
+            Self::YMD(ymd) if !(1..=12).contains(&ymd.month) => {
+                (ymd.year, true, ymd.month as u16, ymd.day)
+            }
+            _ => {
+                let o = ODate::from(*d);
+                (o.year, false, o.day, 0)
+            }
+        };
+        day(self)
+            .cmp(&day(other))
+            .then_with(|| rank(self).cmp(&rank(other)))
+            .then_with(|| match (self, other) {
+                (Self::YMD(a), Self::YMD(b)) => a.cmp(b),
+                (Self::WD(a), Self::WD(b)) => a.cmp(b),
+                (Self::O(a), Self::O(b)) => a.cmp(b),
+                _ => core::cmp::Ordering::Equal,
+            })
+    }
+}
+
+impl<Y> PartialOrd for Date<Y>
+where
+    Y: Year + Ord + Copy,
+    ODate<Y>: From<Date<Y>>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<Y> From<YmdDate<Y>> for YmDate<Y>
 where
     Y: Year,
@@ -350,6 +883,41 @@ where
     }
 }
 
+/// First year of the century, which is a multiple of 100
+impl From<CDate> for YDate {
+    #[inline]
+    fn from(date: CDate) -> Self {
+        Self {
+            year: date.century as i32 * 100,
+        }
+    }
+}
+
+/// Floors the year to its century,
+/// or [`Error::InvalidDate`](crate::Error::InvalidDate) if the century does not fit.
+impl TryFrom<YDate> for CDate {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(date: YDate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            century: date
+                .year
+                .div_euclid(100)
+                .try_into()
+                .or(Err(crate::Error::InvalidDate))?,
+        })
+    }
+}
+
+/// Last year of the century, just before the next multiple of 100
+#[inline]
+pub fn last_year_of_century(c: CDate) -> YDate {
+    YDate {
+        year: (c.century as i32 + 1) * 100 - 1,
+    }
+}
+
 impl<Y> From<WdDate<Y>> for WDate<Y>
 where
     Y: Year,
@@ -374,11 +942,14 @@ where
     }
 }
 
+/// Panics if the day is not within the year,
+/// which [`Valid`] checks beforehand.
 impl<Y> From<ODate<Y>> for YmdDate<Y>
 where
     Y: Year,
 {
     #[inline]
+    #[allow(overlapping_range_endpoints)]
     fn from(date: ODate<Y>) -> Self {
         let leap = date.year.is_leap();
         let (month, day) = match date.day {
@@ -471,23 +1042,37 @@ where
     }
 }
 
+/// ISO weekday of January 1st, from 1 for Monday to 7 for Sunday,
+/// given the previous year modulo 400
+#[inline]
+fn weekday_jan1(previous_year: i64) -> i64 {
+    // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Gauss's_algorithm
+    let y = previous_year;
+    (1 + 5 * (y % 4) + 4 * (y % 100) + 6 * y + 6) % 7 + 1
+}
+
 macro_rules! impl_wd_from_o {
     ($ty:ty) => {
+        /// The week-numbering year differs from the calendar year
+        /// for days before the first Thursday or after the last one.
         impl From<ODate<$ty>> for WdDate<$ty> {
             #[inline]
             fn from(date: ODate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_of_a_given_date
-                let y = date.year % 100 % 28;
-                let cc = (date.year / 100) % 4;
-                let mut c = ((y + (y - 1) / 4 + 5 * cc - 1) % 7) as i16;
-                if c > 3 {
-                    c -= 7;
-                }
-                let dc = date.day as i16 + c;
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_from_an_ordinal_date
+                let jan1 = weekday_jan1((date.year - 1).rem_euclid(400) as i64);
+                let day = (jan1 + date.day as i64 - 2) % 7 + 1;
+                let week = (date.day as i64 - day + 10) / 7;
+                let (year, week) = if week < 1 {
+                    (date.year - 1, (date.year - 1).num_weeks())
+                } else if week > date.year.num_weeks() as i64 {
+                    (date.year + 1, 1)
+                } else {
+                    (date.year, week as u8)
+                };
                 Self {
-                    year: date.year,
-                    week: (dc as f32 / 7.).ceil() as u8,
-                    day: (dc % 7) as u8,
+                    year,
+                    week,
+                    day: day as u8,
                 }
             }
         }
@@ -495,6 +1080,8 @@ macro_rules! impl_wd_from_o {
 }
 impl_years!(impl_wd_from_o);
 
+/// Panics if the month is out of range,
+/// which [`Valid`] checks beforehand.
 impl<Y> From<YmdDate<Y>> for ODate<Y>
 where
     Y: Year,
@@ -535,34 +1122,24 @@ where
 
 macro_rules! impl_o_from_wd {
     ($ty:ty) => {
+        /// Days of the first and last weeks may fall
+        /// in the previous or next calendar year.
         impl From<WdDate<$ty>> for ODate<$ty> {
             #[inline]
             fn from(date: WdDate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_a_date_given_the_year,_week_number_and_weekday
-
-                #[inline]
-                fn weekday_jan4(year: $ty) -> u8 {
-                    #[inline]
-                    fn weekday_jan1(year: $ty) -> u8 {
-                        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Gauss's_algorithm
-                        let y = year - 1;
-                        ((1 + 5 * (y % 4) + 4 * (y % 100) + 6 * (y % 400)) % 7) as u8
-                    }
-
-                    (weekday_jan1(year) + 3) % 7
-                }
-
-                let mut day = (date.week * 7 + date.day - (weekday_jan4(date.year) + 3)) as u16;
-                if day < 1 {
-                    day += (date.year - 1).num_days();
-                }
-                if day > date.year.num_days() {
-                    day -= date.year.num_days();
-                }
-
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_an_ordinal_or_month_date_from_a_week_date
+                let jan4 = (weekday_jan1((date.year - 1).rem_euclid(400) as i64) + 2) % 7 + 1;
+                let day = date.week as i64 * 7 + date.day as i64 - (jan4 + 3);
+                let (year, day) = if day < 1 {
+                    (date.year - 1, day + (date.year - 1).num_days() as i64)
+                } else if day > date.year.num_days() as i64 {
+                    (date.year + 1, day - date.year.num_days() as i64)
+                } else {
+                    (date.year, day)
+                };
                 Self {
-                    year: date.year,
-                    day,
+                    year,
+                    day: day as u16,
                 }
             }
         }
@@ -570,11 +1147,54 @@ macro_rules! impl_o_from_wd {
 }
 impl_years!(impl_o_from_wd);
 
-impl<Y: Year> From<YmDate<Y>> for YmdDate<Y> {
-    fn from(date: YmDate<Y>) -> Self {
-        Self {
-            year: date.year,
-            month: date.month,
+/// Calendar date of a date in any representation, or
+/// [`Error::InvalidDate`](crate::Error::InvalidDate) if it is not valid,
+/// where the [`From`] impls would panic or return a meaningless date.
+///
+/// ```
+/// use iso_8601::*;
+///
+/// let date: Date = "2020-W53-5".parse().unwrap();
+/// assert_eq!(to_calendar_date(date).unwrap().to_string(), "2021-01-01");
+/// assert!(to_calendar_date(Date::O(ODate { year: 2021, day: 366 })).is_err());
+/// ```
+pub fn to_calendar_date<Y>(date: Date<Y>) -> Result<YmdDate<Y>, crate::Error>
+where
+    Y: Year + Clone,
+    YmdDate<Y>: From<Date<Y>>,
+{
+    date.is_valid()
+        .then(|| date.into())
+        .ok_or(crate::Error::InvalidDate)
+}
+
+/// Week date of a date in any representation, like [`to_calendar_date`]
+pub fn to_week_date<Y>(date: Date<Y>) -> Result<WdDate<Y>, crate::Error>
+where
+    Y: Year + Clone,
+    WdDate<Y>: From<Date<Y>>,
+{
+    date.is_valid()
+        .then(|| date.into())
+        .ok_or(crate::Error::InvalidDate)
+}
+
+/// Ordinal date of a date in any representation, like [`to_calendar_date`]
+pub fn to_ordinal_date<Y>(date: Date<Y>) -> Result<ODate<Y>, crate::Error>
+where
+    Y: Year + Clone,
+    ODate<Y>: From<Date<Y>>,
+{
+    date.is_valid()
+        .then(|| date.into())
+        .ok_or(crate::Error::InvalidDate)
+}
+
+impl<Y: Year> From<YmDate<Y>> for YmdDate<Y> {
+    fn from(date: YmDate<Y>) -> Self {
+        Self {
+            year: date.year,
+            month: date.month,
             day: 1,
         }
     }
@@ -600,6 +1220,116 @@ impl<Y: Year> From<YDate<Y>> for YmdDate<Y> {
     }
 }
 
+impl<Y: Year> ApproxDate<Y> {
+    /// Narrows to a [`Date`] without filling in missing components,
+    /// or [`Error::InvalidFormat`](crate::Error::InvalidFormat) for reduced accuracy variants.
+    /// Use the [`From`] impl on [`Date`] to fill them in instead.
+    pub fn try_into_date(self) -> Result<Date<Y>, crate::Error> {
+        match self {
+            ApproxDate::YMD(d) => Ok(Date::YMD(d)),
+            ApproxDate::WD(d) => Ok(Date::WD(d)),
+            ApproxDate::O(d) => Ok(Date::O(d)),
+            ApproxDate::YM(_) | ApproxDate::Y(_) | ApproxDate::C(_) | ApproxDate::W(_) => {
+                Err(crate::Error::InvalidFormat { offset: None })
+            }
+        }
+    }
+}
+
+impl ApproxDate {
+    /// First day of the period designated by the date.
+    ///
+    /// Omitted months and days are the first ones, an omitted week day is
+    /// Monday, and a century starts with the year divisible by 100,
+    /// so `2018-08` resolves to `2018-08-01` and `20` to `2000-01-01`.
+    /// Complete dates resolve to themselves.
+    ///
+    /// Panics on week and ordinal dates outside of their year,
+    /// like the conversions to [`YmdDate`]; check [`Valid`] beforehand.
+    pub fn resolve_start(&self) -> YmdDate {
+        match *self {
+            ApproxDate::YMD(d) => d,
+            ApproxDate::WD(d) => d.into(),
+            ApproxDate::O(d) => d.into(),
+            ApproxDate::YM(d) => d.into(),
+            ApproxDate::W(d) => WdDate::from(d).into(),
+            ApproxDate::Y(d) => d.into(),
+            ApproxDate::C(d) => YDate::from(d).into(),
+        }
+    }
+
+    /// Last day of the period designated by the date.
+    ///
+    /// Omitted months are December, omitted days are the last day of the
+    /// month, an omitted week day is Sunday, and a century ends with the
+    /// year ending in 99, so `2018-08` resolves to `2018-08-31`
+    /// and `20` to `2099-12-31`. Complete dates resolve to themselves.
+    ///
+    /// Panics like [`resolve_start`](Self::resolve_start).
+    pub fn resolve_end(&self) -> YmdDate {
+        let last_day = |year| YmdDate {
+            year,
+            month: 12,
+            day: 31,
+        };
+        match *self {
+            ApproxDate::YM(d) => {
+                let mut date = YmdDate::from(d);
+                date.day = date.days_in_month().unwrap_or(date.day);
+                date
+            }
+            ApproxDate::W(d) => WdDate {
+                year: d.year,
+                week: d.week,
+                day: 7,
+            }
+            .into(),
+            ApproxDate::Y(d) => last_day(d.year),
+            ApproxDate::C(d) => last_day(last_year_of_century(d).year),
+            _ => self.resolve_start(),
+        }
+    }
+}
+
+/// Which day of the period designated by a reduced accuracy date
+/// stands for it, like a month resolving to its first or last day
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug, Default)]
+pub enum Resolution {
+    /// The first day, as given by [`ApproxDate::resolve_start`]
+    #[default]
+    Start,
+    /// The last day, as given by [`ApproxDate::resolve_end`]
+    End,
+}
+
+impl ApproxDate {
+    /// First or last day of the period designated by the date
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let month: ApproxDate = "2016-02".parse().unwrap();
+    /// assert_eq!(month.resolve(Resolution::End).to_string(), "2016-02-29");
+    /// ```
+    #[inline]
+    pub fn resolve(&self, resolution: Resolution) -> YmdDate {
+        match resolution {
+            Resolution::Start => self.resolve_start(),
+            Resolution::End => self.resolve_end(),
+        }
+    }
+}
+
+/// Fills in omitted components like [`ApproxDate::resolve_start`].
+impl From<ApproxDate> for YmdDate {
+    #[inline]
+    fn from(date: ApproxDate) -> Self {
+        date.resolve_start()
+    }
+}
+
+/// Fills in omitted components like [`ApproxDate::resolve_start`],
+/// keeping week and ordinal dates in their representation.
 impl<Y: Year + From<i16>> From<ApproxDate<Y>> for Date<Y> {
     #[inline]
     fn from(date: ApproxDate<Y>) -> Self {
@@ -612,7 +1342,7 @@ impl<Y: Year + From<i16>> From<ApproxDate<Y>> for Date<Y> {
             ApproxDate::Y(d) => Date::YMD(d.into()),
             ApproxDate::C(d) => Date::YMD(
                 YDate {
-                    year: Y::from(d.century as i16 * 100 + 1),
+                    year: Y::from(d.century as i16 * 100),
                 }
                 .into(),
             ),
@@ -620,10 +1350,309 @@ impl<Y: Year + From<i16>> From<ApproxDate<Y>> for Date<Y> {
     }
 }
 
+impl YmdDate {
+    /// Number of days since 1970-01-01 in the proleptic Gregorian calendar
+    #[inline]
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        // https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`days_since_epoch`](Self::days_since_epoch),
+    /// or `None` if the year does not fit.
+    #[inline]
+    pub(crate) fn from_days_since_epoch(days: i64) -> Option<Self> {
+        // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days.checked_add(719_468)?;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        Some(Self {
+            year: year.try_into().ok()?,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+
+    /// Day of the week, in the proleptic Gregorian calendar
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let date: YmdDate = "2024-07-04".parse().unwrap();
+    /// assert_eq!(date.weekday(), Weekday::Thursday);
+    /// ```
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_days_since_epoch(self.days_since_epoch())
+    }
+}
+
+impl ODate {
+    /// Day of the week, in the proleptic Gregorian calendar
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        let jan1 = YmdDate {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        Weekday::from_days_since_epoch(jan1.days_since_epoch() + self.day as i64 - 1)
+    }
+}
+
+impl Date {
+    /// Day of the week, in the proleptic Gregorian calendar
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        match self {
+            Date::YMD(date) => date.weekday(),
+            Date::WD(date) => ODate::from(*date).weekday(),
+            Date::O(date) => date.weekday(),
+        }
+    }
+
+    /// The following day, in the same representation,
+    /// or `None` if its year does not fit.
+    #[inline]
+    pub(crate) fn next_day(self) -> Option<Self> {
+        let ymd = YmdDate::from_days_since_epoch(YmdDate::from(self).days_since_epoch() + 1)?;
+        Some(self.with_ymd(ymd))
+    }
+
+    /// Replaces the date, keeping the representation
+    #[inline]
+    pub(crate) fn with_ymd(self, ymd: YmdDate) -> Self {
+        match self {
+            Date::YMD(_) => Date::YMD(ymd),
+            Date::WD(_) => Date::WD(ymd.into()),
+            Date::O(_) => Date::O(ymd.into()),
+        }
+    }
+}
+
+/// An inclusive range of years
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct YearRange {
+    start: i32,
+    end: i32,
+    exhausted: bool,
+}
+
+impl YearRange {
+    #[inline]
+    pub fn new(start: i32, end: i32) -> Result<Self, crate::Error> {
+        (start <= end)
+            .then_some(Self {
+                start,
+                end,
+                exhausted: false,
+            })
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+/// Every year from `start` to `end` inclusive,
+/// or none if `end` precedes `start`.
+#[inline]
+pub fn years_in_range(start: YDate, end: YDate) -> YearRange {
+    YearRange {
+        start: start.year,
+        end: end.year,
+        exhausted: start.year > end.year,
+    }
+}
+
+impl Iterator for YearRange {
+    type Item = YDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<YDate> {
+        if self.exhausted {
+            return None;
+        }
+        let year = self.start;
+        if self.start == self.end {
+            self.exhausted = true;
+        } else {
+            self.start += 1;
+        }
+        Some(YDate { year })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.exhausted {
+            0
+        } else {
+            (self.end as i64 - self.start as i64) as usize + 1
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for YearRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<YDate> {
+        if self.exhausted {
+            return None;
+        }
+        let year = self.end;
+        if self.start == self.end {
+            self.exhausted = true;
+        } else {
+            self.end -= 1;
+        }
+        Some(YDate { year })
+    }
+}
+
+impl ExactSizeIterator for YearRange {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn century() {
+        assert_eq!(YDate::from(CDate { century: 20 }), YDate { year: 2000 });
+        assert_eq!(YDate::from(CDate { century: -1 }), YDate { year: -100 });
+        assert_eq!(YDate::from(CDate { century: 0 }), YDate { year: 0 });
+
+        assert_eq!(
+            CDate::try_from(YDate { year: 1999 }).unwrap(),
+            CDate { century: 19 }
+        );
+        assert_eq!(
+            CDate::try_from(YDate { year: 2000 }).unwrap(),
+            CDate { century: 20 }
+        );
+        assert_eq!(
+            CDate::try_from(YDate { year: -1 }).unwrap(),
+            CDate { century: -1 }
+        );
+        assert!(matches!(
+            CDate::try_from(YDate { year: 12_800 }),
+            Err(crate::Error::InvalidDate)
+        ));
+
+        assert_eq!(
+            last_year_of_century(CDate { century: 20 }),
+            YDate { year: 2099 }
+        );
+        assert_eq!(
+            last_year_of_century(CDate { century: -1 }),
+            YDate { year: -1 }
+        );
+        assert_eq!(
+            last_year_of_century(CDate { century: i8::MAX }),
+            YDate { year: 12_799 }
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let resolve = |s: &str| {
+            let date = s.parse::<ApproxDate>().unwrap();
+            (
+                date.resolve_start().to_string(),
+                date.resolve_end().to_string(),
+            )
+        };
+        let range = |start: &str, end: &str| (start.to_string(), end.to_string());
+
+        assert_eq!(resolve("2018-08"), range("2018-08-01", "2018-08-31"));
+        assert_eq!(resolve("2016-02"), range("2016-02-01", "2016-02-29"));
+        assert_eq!(resolve("2018-09"), range("2018-09-01", "2018-09-30"));
+        assert_eq!(resolve("2018"), range("2018-01-01", "2018-12-31"));
+        assert_eq!(resolve("20"), range("2000-01-01", "2099-12-31"));
+        assert_eq!(resolve("2018-W31"), range("2018-07-30", "2018-08-05"));
+        assert_eq!(resolve("2018-08-02"), range("2018-08-02", "2018-08-02"));
+        assert_eq!(resolve("2018-214"), range("2018-08-02", "2018-08-02"));
+        for s in ["2018-08", "2018", "20", "2018-W31", "2018-214"] {
+            let date = s.parse::<ApproxDate>().unwrap();
+            assert_eq!(YmdDate::from(Date::from(date)), date.resolve_start());
+            assert_eq!(date.resolve(Resolution::Start), date.resolve_start());
+            assert_eq!(date.resolve(Resolution::End), date.resolve_end());
+        }
+        assert_eq!(Resolution::default(), Resolution::Start);
+        assert_eq!(
+            Date::from(ApproxDate::C(CDate { century: 20 })),
+            Date::YMD(YmdDate {
+                year: 2000,
+                month: 1,
+                day: 1,
+            })
+        );
+        assert_eq!(
+            YmdDate::from("2018-08".parse::<ApproxDate>().unwrap()),
+            YmdDate {
+                year: 2018,
+                month: 8,
+                day: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn try_into_date() {
+        let ymd = YmdDate {
+            year: 2018,
+            month: 8,
+            day: 2,
+        };
+        let wd = WdDate {
+            year: 2018,
+            week: 31,
+            day: 4,
+        };
+        let o = ODate {
+            year: 2018,
+            day: 214,
+        };
+        assert_eq!(
+            ApproxDate::YMD(ymd).try_into_date().unwrap(),
+            Date::YMD(ymd)
+        );
+        assert_eq!(ApproxDate::WD(wd).try_into_date().unwrap(), Date::WD(wd));
+        assert_eq!(ApproxDate::O(o).try_into_date().unwrap(), Date::O(o));
+
+        for date in [
+            ApproxDate::YM(YmDate {
+                year: 2018,
+                month: 8,
+            }),
+            ApproxDate::Y(YDate { year: 2018 }),
+            ApproxDate::C(CDate { century: 20 }),
+            ApproxDate::W(WDate {
+                year: 2018,
+                week: 31,
+            }),
+        ] {
+            assert!(matches!(
+                date.try_into_date(),
+                Err(crate::Error::InvalidFormat { .. })
+            ));
+        }
+    }
+
     #[test]
     fn ymd_from_wd() {
         assert_eq!(
@@ -655,6 +1684,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ymd_o_boundaries() {
+        for (year, day, month, day_of_month) in [
+            (2021, 1, 1, 1),
+            (2021, 59, 2, 28),
+            (2021, 60, 3, 1),
+            (2021, 365, 12, 31),
+            (2020, 1, 1, 1),
+            (2020, 59, 2, 28),
+            (2020, 60, 2, 29),
+            (2020, 61, 3, 1),
+            (2020, 366, 12, 31),
+            (1900, 60, 3, 1),
+            (2000, 60, 2, 29),
+        ] {
+            let o = ODate { year, day };
+            let ymd = YmdDate {
+                year,
+                month,
+                day: day_of_month,
+            };
+            assert_eq!(YmdDate::from(o), ymd);
+            assert_eq!(ODate::from(ymd), o);
+        }
+        assert_eq!(
+            YmdDate::from("2020-366".parse::<ODate>().unwrap()).to_string(),
+            "2020-12-31"
+        );
+        assert!(matches!(
+            "2021-366".parse::<ODate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
     #[test]
     fn wd_from_ymd() {
         assert_eq!(
@@ -683,6 +1746,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ymd_wd_week_year_boundaries() {
+        for (ymd, wd) in [
+            ("2021-01-01", "2020-W53-5"),
+            ("2020-12-31", "2020-W53-4"),
+            ("2021-01-03", "2020-W53-7"),
+            ("2021-01-04", "2021-W01-1"),
+            ("2019-12-30", "2020-W01-1"),
+            ("2008-12-29", "2009-W01-1"),
+            ("2010-01-03", "2009-W53-7"),
+            ("2005-01-01", "2004-W53-6"),
+            ("2007-01-01", "2007-W01-1"),
+            ("2024-12-31", "2025-W01-2"),
+            ("-0001-01-01", "-0002-W53-5"),
+            ("-0001-01-04", "-0001-W01-1"),
+        ] {
+            let ymd: YmdDate = ymd.parse().unwrap();
+            let wd: WdDate = wd.parse().unwrap();
+            assert_eq!(WdDate::from(ymd), wd);
+            assert_eq!(YmdDate::from(wd), ymd);
+        }
+    }
+
     #[test]
     fn wd_from_o() {
         assert_eq!(
@@ -749,6 +1835,327 @@ mod tests {
             day: 29
         }
         .is_valid());
+        assert!(!YmdDate {
+            year: 2015,
+            month: 2,
+            day: 29
+        }
+        .is_valid());
+        assert!(YmdDate {
+            year: 2016,
+            month: 2,
+            day: 29
+        }
+        .is_valid());
+        assert!(YmdDate {
+            year: 2000,
+            month: 2,
+            day: 29
+        }
+        .is_valid());
+        assert!(!YmdDate {
+            year: 1900,
+            month: 2,
+            day: 29
+        }
+        .is_valid());
+        assert!(!YmdDate {
+            year: 2018,
+            month: 4,
+            day: 31
+        }
+        .is_valid());
+        assert!("2016-02-29".parse::<YmdDate>().is_ok());
+        assert!("2015-02-29".parse::<YmdDate>().is_err());
+        assert!("2019-02-29".parse::<YmdDate>().is_err());
+        assert!("2019-02-00".parse::<YmdDate>().is_err());
+
+        for (month, days) in [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+            .into_iter()
+            .enumerate()
+        {
+            let date = |day| YmdDate {
+                year: 2019,
+                month: month as u8 + 1,
+                day,
+            };
+            assert!(date(days).is_valid(), "{:?}", date(days));
+            assert!(!date(days + 1).is_valid(), "{:?}", date(days + 1));
+        }
+
+        // proleptic Gregorian leap years before year 1
+        for (year, leap) in [(0, true), (-4, true), (-100, false), (-400, true)] {
+            assert_eq!(
+                YmdDate {
+                    year,
+                    month: 2,
+                    day: 29
+                }
+                .is_valid(),
+                leap,
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            YmdDate::new(2016, 2, 29).unwrap(),
+            YmdDate {
+                year: 2016,
+                month: 2,
+                day: 29
+            }
+        );
+        assert!(YmdDate::new(2015, 2, 29).is_err());
+        assert!(YmdDate::new(2015, 13, 40).is_err());
+        assert!(YmDate::new(2015, 12).is_ok());
+        assert!(YmDate::new(2015, 0).is_err());
+        assert!(WdDate::new(2015, 53, 7).is_ok());
+        assert!(WdDate::new(2016, 53, 1).is_err());
+        assert!(WdDate::new(2015, 1, 8).is_err());
+        assert!(WDate::new(2020, 53).is_ok());
+        assert!(WDate::new(2021, 53).is_err());
+        assert!(ODate::new(2016, 366).is_ok());
+        assert!(ODate::new(2015, 366).is_err());
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            YmdDate::try_from("2016-02-29").unwrap(),
+            "2016-02-29".parse::<YmdDate>().unwrap()
+        );
+        assert!(matches!(
+            YmdDate::try_from("2015-02-29"),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            Date::try_from("2015-02"),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_expanded() {
+        use crate::ParseExpanded;
+
+        assert_eq!(
+            YmdDate::parse_expanded("+0120240229", 2).unwrap(),
+            YmdDate {
+                year: 12_024,
+                month: 2,
+                day: 29
+            }
+        );
+        assert_eq!(WdDate::parse_expanded("-00012W011", 1).unwrap().year, -12);
+        assert_eq!(
+            ODate::parse_expanded("+001000004366", 5).unwrap().year,
+            1_000_004
+        );
+        assert!(matches!(
+            YmdDate::parse_expanded("+0120230229", 2),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            ODate::parse_expanded("+0120230001", 2),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn conversions() {
+        for year in [-401, -1, 0, 1900, 2000, 2004, 2020, 2021, 2100] {
+            for day in 1..=year.num_days() {
+                let o = ODate { year, day };
+                let ymd = YmdDate::from(o);
+                let wd = WdDate::from(o);
+                assert!(ymd.is_valid() && wd.is_valid(), "{:?}", o);
+
+                assert_eq!(ODate::from(ymd), o);
+                assert_eq!(ODate::from(wd), o);
+                assert_eq!(YmdDate::from(wd), ymd);
+                assert_eq!(WdDate::from(ymd), wd);
+
+                for date in [Date::YMD(ymd), Date::WD(wd), Date::O(o)] {
+                    assert_eq!(to_calendar_date(date).unwrap(), ymd);
+                    assert_eq!(to_week_date(date).unwrap(), wd);
+                    assert_eq!(to_ordinal_date(date).unwrap(), o);
+                }
+            }
+        }
+
+        for date in [
+            Date::YMD(YmdDate {
+                year: 2021,
+                month: 2,
+                day: 29,
+            }),
+            Date::WD(WdDate {
+                year: 2021,
+                week: 53,
+                day: 1,
+            }),
+            Date::O(ODate { year: 2021, day: 0 }),
+        ] {
+            assert!(matches!(
+                to_calendar_date(date),
+                Err(crate::Error::InvalidDate)
+            ));
+            assert!(matches!(to_week_date(date), Err(crate::Error::InvalidDate)));
+            assert!(matches!(
+                to_ordinal_date(date),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let mut dates: Vec<Date> = [
+            "2021-001",
+            "2020-W53-5",
+            "2021-01-01",
+            "2020-12-31",
+            "2020-W53-4",
+            "2020-366",
+            "-0001-W52-7",
+            "2021-01-02",
+            "1900-W01-1",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        dates.sort();
+        let sorted: Vec<String> = dates.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            sorted,
+            [
+                "-0001-W52-7",
+                "1900-W01-1",
+                "2020-12-31",
+                "2020-W53-4",
+                "2020-366",
+                "2021-01-01",
+                "2020-W53-5",
+                "2021-001",
+                "2021-01-02",
+            ]
+        );
+
+        let set: std::collections::BTreeSet<Date> = dates.into_iter().collect();
+        assert_eq!(set.len(), 9);
+
+        // the same day in the same representation is ordered by its fields,
+        // even if it is not valid, as long as the conversion does not panic
+        let a = Date::WD(WdDate {
+            year: 2021,
+            week: 1,
+            day: 8,
+        });
+        let b = Date::WD(WdDate {
+            year: 2021,
+            week: 2,
+            day: 1,
+        });
+        assert_eq!(ODate::from(a), ODate::from(b));
+        assert!(a < b);
+
+        // calendar dates with a month out of range do not panic
+        let invalid = Date::YMD(YmdDate {
+            year: 2020,
+            month: 13,
+            day: 1,
+        });
+        let o = Date::O(ODate {
+            year: 2020,
+            day: 366,
+        });
+        assert_eq!(invalid.cmp(&o), core::cmp::Ordering::Greater);
+        assert_eq!(o.cmp(&invalid), core::cmp::Ordering::Less);
+        assert!(invalid < Date::O(ODate { year: 2021, day: 1 }));
+        assert!(
+            Date::YMD(YmdDate {
+                year: 2020,
+                month: 0,
+                day: 1
+            }) < invalid
+        );
+
+        // the derived orderings of each representation are chronological
+        for year in [-1, 2000, 2020, 2021] {
+            for day in 1..year.num_days() {
+                let (o, next) = (ODate { year, day }, ODate { year, day: day + 1 });
+                assert!(o < next);
+                assert!(YmdDate::from(o) < YmdDate::from(next));
+                assert!(WdDate::from(o) < WdDate::from(next));
+                assert!(Date::WD(o.into()) < Date::YMD(next.into()));
+            }
+        }
+    }
+
+    #[test]
+    fn weekday() {
+        for (date, weekday) in [
+            ("1970-01-01", Weekday::Thursday),
+            ("2000-01-01", Weekday::Saturday),
+            ("2000-02-29", Weekday::Tuesday),
+            ("2024-07-04", Weekday::Thursday),
+            ("1582-10-15", Weekday::Friday),
+            ("0000-01-01", Weekday::Saturday),
+            ("-0001-12-31", Weekday::Friday),
+        ] {
+            assert_eq!(date.parse::<YmdDate>().unwrap().weekday(), weekday);
+            assert_eq!(date.parse::<Date>().unwrap().weekday(), weekday);
+        }
+        assert_eq!(
+            ODate {
+                year: 2020,
+                day: 366
+            }
+            .weekday(),
+            Weekday::Thursday
+        );
+
+        for year in [-401, -1, 0, 1900, 2000, 2020, 2021] {
+            for day in 1..=year.num_days() {
+                let o = ODate { year, day };
+                let weekday = Weekday::try_from(WdDate::from(o).day).unwrap();
+                assert_eq!(o.weekday(), weekday, "{:?}", o);
+                assert_eq!(YmdDate::from(o).weekday(), weekday, "{:?}", o);
+                assert_eq!(Date::WD(o.into()).weekday(), weekday, "{:?}", o);
+            }
+        }
+
+        for number in 1..=7 {
+            assert_eq!(u8::from(Weekday::try_from(number).unwrap()), number);
+        }
+        assert!(matches!(
+            Weekday::try_from(0),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            Weekday::try_from(8),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn calendar() {
+        assert!(is_leap_year(2016));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2015));
+
+        assert_eq!(days_in_month(2016, 2), Some(29));
+        assert_eq!(days_in_month(2015, 2), Some(28));
+        assert_eq!(days_in_month(2015, 1), Some(31));
+        assert_eq!(days_in_month(2015, 4), Some(30));
+        assert_eq!(days_in_month(2015, 0), None);
+        assert_eq!(days_in_month(2015, 13), None);
     }
 
     #[test]
@@ -780,6 +2187,59 @@ mod tests {
         .is_valid());
     }
 
+    #[test]
+    fn valid_date_w() {
+        assert!(!WDate {
+            year: 2020,
+            week: 0
+        }
+        .is_valid());
+        assert!(WDate {
+            year: 2020,
+            week: 53
+        }
+        .is_valid());
+        assert!(!WDate {
+            year: 2020,
+            week: 54
+        }
+        .is_valid());
+        assert!(!WDate {
+            year: 2021,
+            week: 53
+        }
+        .is_valid());
+        assert!(matches!(
+            "2021-W53-1".parse::<WdDate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn num_weeks() {
+        let long = [
+            2004, 2009, 2015, 2020, 2026, 2032, 2037, 2043, 2048, 2054, 2060, 2065, 2071, 2076,
+            2082, 2088, 2093, 2099,
+        ];
+        for year in 2000..=2100 {
+            let weeks = if long.contains(&year) { 53 } else { 52 };
+            assert_eq!(year.num_weeks(), weeks, "{}", year);
+            assert_eq!((year as u16).num_weeks(), weeks, "{}", year);
+            assert_eq!((year as i64 - 400).num_weeks(), weeks, "{}", year);
+        }
+        assert_eq!(
+            (0..400).filter(|year: &i32| year.num_weeks() == 53).count(),
+            71
+        );
+
+        assert_eq!(0u16.num_weeks(), 0i32.num_weeks());
+        assert_eq!(0u64.num_weeks(), (-400i64).num_weeks());
+        for year in [i32::MIN, i32::MAX] {
+            assert!(matches!(year.num_weeks(), 52 | 53));
+        }
+        assert!(matches!(u128::MAX.num_weeks(), 52 | 53));
+    }
+
     #[test]
     fn valid_date_o() {
         assert!(!ODate {
@@ -792,5 +2252,319 @@ mod tests {
             day: 366
         }
         .is_valid());
+
+        assert!("2020-366".parse::<ODate>().is_ok());
+        assert!("2000-366".parse::<ODate>().is_ok());
+        assert!("2021-365".parse::<ODate>().is_ok());
+        assert!(matches!(
+            "2021-366".parse::<ODate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            "1900-366".parse::<ODate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            "2021-000".parse::<ODate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+
+        assert!("2020366".parse::<Date>().is_ok());
+        assert!(matches!(
+            "2021366".parse::<Date>(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            "2021-366".parse::<ApproxDate>(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!("2020-366T12:00Z"
+            .parse::<crate::DateTime<Date, crate::ApproxGlobalTime>>()
+            .is_ok());
+        assert!(matches!(
+            "2021-366T12:00Z".parse::<crate::DateTime<Date, crate::ApproxGlobalTime>>(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(ODate::new(2020, 366).is_ok());
+        assert!(ODate::new(2021, 366).is_err());
+    }
+
+    #[test]
+    fn year_range() {
+        let years: Vec<_> = YearRange::new(-2, 2).unwrap().map(|d| d.year).collect();
+        assert_eq!(years, [-2, -1, 0, 1, 2]);
+
+        let years: Vec<_> = YearRange::new(-2, 2)
+            .unwrap()
+            .rev()
+            .map(|d| d.year)
+            .collect();
+        assert_eq!(years, [2, 1, 0, -1, -2]);
+
+        let mut range = YearRange::new(i32::MAX - 1, i32::MAX).unwrap();
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.next_back(), Some(YDate { year: i32::MAX }));
+        assert_eq!(range.next(), Some(YDate { year: i32::MAX - 1 }));
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        assert_eq!(YearRange::new(2000, 2000).unwrap().len(), 1);
+        assert!(YearRange::new(2001, 2000).is_err());
+    }
+
+    #[test]
+    fn years_in_range() {
+        let mut range = super::years_in_range(YDate { year: 1999 }, YDate { year: 2002 });
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.next_back(), Some(YDate { year: 2002 }));
+
+        assert_eq!(
+            super::years_in_range(YDate { year: 2002 }, YDate { year: 1999 }).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn dateable() {
+        fn components<D: Datelike>(date: &D) -> (i32, Option<u8>, Option<u8>) {
+            (date.year(), date.month(), date.day())
+        }
+
+        assert_eq!(
+            components(&Date::O(ODate {
+                year: 1985,
+                day: 102
+            })),
+            (1985, Some(4), Some(12))
+        );
+        assert_eq!(
+            components(&Date::WD(WdDate {
+                year: 1985,
+                week: 15,
+                day: 5
+            })),
+            (1985, Some(4), Some(12))
+        );
+        assert_eq!(
+            components(&ApproxDate::YM(YmDate {
+                year: 2018,
+                month: 8
+            })),
+            (2018, Some(8), None)
+        );
+        assert_eq!(
+            components(&ApproxDate::W(WDate {
+                year: 2018,
+                week: 8
+            })),
+            (2018, None, None)
+        );
+        assert_eq!(components(&YDate { year: 2018 }), (2018, None, None));
+        assert_eq!(components(&CDate { century: 20 }), (2000, None, None));
+
+        // the first days of 2021 belong to the last week of 2020
+        let first: Date = "2020-W53-5".parse().unwrap();
+        assert_eq!(components(&first), (2021, Some(1), Some(1)));
+        assert_eq!(first.ordinal(), Some(1));
+        assert_eq!(
+            first.iso_week(),
+            Some(WDate {
+                year: 2020,
+                week: 53
+            })
+        );
+        for s in ["2021-01-01", "2021-001"] {
+            let date: Date = s.parse().unwrap();
+            assert_eq!(date.ordinal(), Some(1));
+            assert_eq!(date.iso_week(), first.iso_week());
+        }
+
+        let last: crate::DateTime<ApproxDate, crate::ApproxAnyTime> =
+            "2024-12-31T12:00".parse().unwrap();
+        assert_eq!(last.ordinal(), Some(366));
+        assert_eq!(
+            last.iso_week(),
+            Some(WDate {
+                year: 2025,
+                week: 1
+            })
+        );
+
+        let week = ApproxDate::W(WDate {
+            year: 2018,
+            week: 8,
+        });
+        assert_eq!(week.ordinal(), None);
+        assert_eq!(
+            week.iso_week(),
+            Some(WDate {
+                year: 2018,
+                week: 8
+            })
+        );
+        assert_eq!(
+            ApproxDate::YM(YmDate {
+                year: 2018,
+                month: 8
+            })
+            .iso_week(),
+            None
+        );
+        assert_eq!(YDate { year: 2018 }.ordinal(), None);
+
+        use crate::ParseLenient;
+        let ymd = YmdDate::parse_lenient("2024-13-01").unwrap();
+        assert_eq!((ymd.ordinal(), ymd.iso_week()), (None, None));
+        let o = ODate::parse_lenient("2024-400").unwrap();
+        assert_eq!(components(&o), (2024, None, None));
+        assert_eq!((o.ordinal(), o.iso_week()), (None, None));
+        let wd = WdDate {
+            year: 2021,
+            week: 60,
+            day: 1,
+        };
+        assert_eq!(components(&wd), (2021, None, None));
+        assert_eq!((wd.ordinal(), wd.iso_week()), (None, None));
+        let w = WDate {
+            year: 2021,
+            week: 60,
+        };
+        assert_eq!(w.iso_week(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Date::WD(WdDate {
+                year: 2024,
+                week: 27,
+                day: 4
+            })
+            .to_string(),
+            "2024-W27-4"
+        );
+        assert_eq!(
+            Date::O(ODate {
+                year: 2024,
+                day: 186
+            })
+            .to_string(),
+            "2024-186"
+        );
+        assert_eq!(
+            YmdDate {
+                year: -333,
+                month: 6,
+                day: 11
+            }
+            .to_string(),
+            "-0333-06-11"
+        );
+
+        for s in ["2024-07-04", "2024-W27-4", "2024-186", "-0333-06-11"] {
+            assert_eq!(s.parse::<Date>().unwrap().to_string(), s);
+        }
+        for s in [
+            "2024-07-04",
+            "2024-07",
+            "2024",
+            "20",
+            "2024-W27-4",
+            "2024-W27",
+            "2024-186",
+        ] {
+            assert_eq!(s.parse::<ApproxDate>().unwrap().to_string(), s);
+        }
+
+        assert_eq!(
+            "20240704".parse::<YmdDate>().unwrap().to_string(),
+            "2024-07-04"
+        );
+        assert_eq!("2024-07".parse::<YmDate>().unwrap().to_string(), "2024-07");
+        assert_eq!("2024".parse::<YDate>().unwrap().to_string(), "2024");
+        assert_eq!("20".parse::<CDate>().unwrap().to_string(), "20");
+        assert_eq!(
+            "2024W274".parse::<WdDate>().unwrap().to_string(),
+            "2024-W27-4"
+        );
+        assert_eq!("2024-W27".parse::<WDate>().unwrap().to_string(), "2024-W27");
+        assert_eq!("2024186".parse::<ODate>().unwrap().to_string(), "2024-186");
+    }
+
+    #[test]
+    fn days_since_epoch() {
+        for (date, days) in [
+            ((1970, 1, 1), 0),
+            ((2000, 3, 1), 11_017),
+            ((1969, 12, 31), -1),
+            ((-4713, 11, 24), -2_440_588),
+        ] {
+            let date = YmdDate {
+                year: date.0,
+                month: date.1,
+                day: date.2,
+            };
+            assert_eq!(date.days_since_epoch(), days);
+            assert_eq!(YmdDate::from_days_since_epoch(days), Some(date));
+        }
+        assert_eq!(YmdDate::from_days_since_epoch(i64::MAX), None);
+        assert_eq!(YmdDate::from_days_since_epoch(1_000_000_000_000), None);
+    }
+
+    #[test]
+    fn ord() {
+        let mut dates: Vec<YmdDate> = ["2018-08-02", "-0333-06-11", "2018-02-12", "2016-02-29"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        dates.sort();
+        assert_eq!(
+            dates.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["-0333-06-11", "2016-02-29", "2018-02-12", "2018-08-02"]
+        );
+
+        assert!(
+            ODate { year: 2018, day: 2 }
+                < ODate {
+                    year: 2018,
+                    day: 10
+                }
+        );
+        assert!(
+            WdDate {
+                year: 2018,
+                week: 52,
+                day: 7
+            } < WdDate {
+                year: 2019,
+                week: 1,
+                day: 1
+            }
+        );
+    }
+
+    #[test]
+    fn display_year_padding() {
+        for (year, s) in [
+            (9, "0009"),
+            (-9, "-0009"),
+            (99, "0099"),
+            (999, "0999"),
+            (-999, "-0999"),
+            (100_000_000, "+100000000"),
+            (-100_000, "-100000"),
+        ] {
+            assert_eq!(YDate { year }.to_string(), s);
+            assert_eq!(
+                YmdDate {
+                    year,
+                    month: 1,
+                    day: 1
+                }
+                .to_string(),
+                format!("{}-01-01", s)
+            );
+        }
     }
 }
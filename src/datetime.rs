@@ -1,6 +1,6 @@
-use crate::{date::*, time::*, Valid};
+use crate::{date::*, time::*, Valid, WriteIso8601};
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub struct DateTime<D = YmdDate, T = GlobalTime>
 where
     D: Datelike,
@@ -12,6 +12,281 @@ where
 
 impl<D: Copy + Datelike, T: Copy + Timelike> Copy for DateTime<D, T> {}
 
+impl<D, T> Datelike for DateTime<D, T>
+where
+    D: Datelike,
+    T: Timelike,
+{
+    #[inline]
+    fn year(&self) -> i32 {
+        self.date.year()
+    }
+
+    #[inline]
+    fn month(&self) -> Option<u8> {
+        self.date.month()
+    }
+
+    #[inline]
+    fn day(&self) -> Option<u8> {
+        self.date.day()
+    }
+
+    #[inline]
+    fn ordinal(&self) -> Option<u16> {
+        self.date.ordinal()
+    }
+
+    #[inline]
+    fn iso_week(&self) -> Option<WDate> {
+        self.date.iso_week()
+    }
+}
+
+impl<D, T> Timelike for DateTime<D, T>
+where
+    D: Datelike,
+    T: Timelike,
+{
+    #[inline]
+    fn hour(&self) -> u8 {
+        self.time.hour()
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        self.time.minute()
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        self.time.second()
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.time.nanosecond()
+    }
+
+    #[inline]
+    fn offset_minutes(&self) -> Option<i16> {
+        self.time.offset_minutes()
+    }
+}
+
+impl<T: Timelike> DateTime<Date, T> {
+    /// The date converted to a calendar date
+    #[inline]
+    pub fn as_ymd(&self) -> YmdDate {
+        self.date.into()
+    }
+
+    /// Replaces the date, keeping the time
+    #[inline]
+    pub fn with_date(self, date: Date) -> Self {
+        Self { date, ..self }
+    }
+}
+
+/// Shifts a date and local time forwards or backwards by a duration.
+///
+/// Years and months are applied first, clamping the day to the length
+/// of the resulting month, so that January 31st plus one month is the
+/// last day of February. The other components then carry over into
+/// the neighbouring days, and the date keeps its representation.
+fn shift(
+    date: Date,
+    local: &LocalTime,
+    duration: &crate::Duration,
+    sign: i8,
+) -> Result<(Date, LocalTime), crate::Error> {
+    const SECONDS_PER_DAY: i128 = 86_400;
+
+    if duration.fraction >= 1_000_000_000 {
+        return Err(crate::Error::InvalidDate);
+    }
+
+    let ymd = YmdDate::from(date);
+    let months = ymd.year as i64 * 12 + ymd.month as i64 - 1
+        + sign as i64 * (duration.years as i64 * 12 + duration.months as i64);
+    let mut ymd = YmdDate {
+        year: months
+            .div_euclid(12)
+            .try_into()
+            .or(Err(crate::Error::InvalidDate))?,
+        month: months.rem_euclid(12) as u8 + 1,
+        day: ymd.day,
+    };
+    ymd.day = ymd.day.min(ymd.days_in_month().unwrap_or(ymd.day));
+
+    let nanos = (sign as i64)
+        .checked_mul(duration.fraction as i64)
+        .and_then(|nanos| nanos.checked_add(local.fraction as i64))
+        .ok_or(crate::Error::InvalidDate)?;
+    let carry = nanos.div_euclid(1_000_000_000) as i128;
+    let fraction = nanos.rem_euclid(1_000_000_000) as u32;
+
+    let naive = &local.naive;
+    let seconds = ymd.days_since_epoch() as i128 * SECONDS_PER_DAY
+        + naive.hour as i128 * 3_600
+        + naive.minute as i128 * 60
+        + naive.second as i128
+        + carry
+        + sign as i128
+            * ((duration.weeks as i128 * 7 + duration.days as i128) * SECONDS_PER_DAY
+                + duration.hours as i128 * 3_600
+                + duration.minutes as i128 * 60
+                + duration.seconds as i128);
+    let day_seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    let ymd = seconds
+        .div_euclid(SECONDS_PER_DAY)
+        .try_into()
+        .ok()
+        .and_then(YmdDate::from_days_since_epoch)
+        .ok_or(crate::Error::InvalidDate)?;
+
+    Ok((
+        date.with_ymd(ymd),
+        LocalTime {
+            naive: HmsTime {
+                hour: (day_seconds / 3_600) as u8,
+                minute: (day_seconds / 60 % 60) as u8,
+                second: (day_seconds % 60) as u8,
+            },
+            fraction,
+            fraction_digits: local.fraction_digits,
+        },
+    ))
+}
+
+impl DateTime<Date, GlobalTime> {
+    /// Adds a duration in the local time of the timezone, applying years and
+    /// months first and clamping the day to the length of the resulting month.
+    /// Fails with [`Error::InvalidDate`](crate::Error::InvalidDate)
+    /// if the year overflows or the fraction of the seconds exceeds a second.
+    pub fn checked_add(&self, duration: &crate::Duration) -> Result<Self, crate::Error> {
+        self.shifted(duration, 1)
+    }
+
+    /// Subtracts a duration, like [`checked_add`](Self::checked_add) backwards
+    pub fn checked_sub(&self, duration: &crate::Duration) -> Result<Self, crate::Error> {
+        self.shifted(duration, -1)
+    }
+
+    /// Seconds since 1970-01-01T00:00:00Z, without the decimal fraction
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        DateTime {
+            date: self.as_ymd(),
+            time: self.time,
+        }
+        .unix_seconds()
+    }
+
+    /// Nanoseconds since 1970-01-01T00:00:00Z
+    #[inline]
+    pub fn timestamp_nanos(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.time.local.nanosecond() as i128
+    }
+
+    /// Time elapsed since `other`, as whole seconds and the remaining
+    /// nanoseconds, both negative if `other` is later.
+    /// Both datetimes are brought to UTC first.
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let a: DateTime<Date, GlobalTime> = "2018-08-02T13:42:00+02:00".parse().unwrap();
+    /// let b: DateTime<Date, GlobalTime> = "2018-08-02T07:42:00.5-05:00".parse().unwrap();
+    /// assert_eq!(a.signed_duration_since(&b), (-3_600, -500_000_000));
+    /// assert_eq!(b.signed_duration_since(&a), (3_600, 500_000_000));
+    /// ```
+    #[inline]
+    pub fn signed_duration_since(&self, other: &Self) -> (i64, i32) {
+        let nanos = self.timestamp_nanos() - other.timestamp_nanos();
+        (
+            (nanos / 1_000_000_000) as i64,
+            (nanos % 1_000_000_000) as i32,
+        )
+    }
+
+    /// UTC calendar date and time from [`timestamp`](Self::timestamp) seconds
+    /// and nanoseconds, or [`Error::InvalidDate`](crate::Error::InvalidDate)
+    /// if the nanoseconds exceed a second or the year does not fit.
+    pub fn from_timestamp(secs: i64, nanos: u32) -> Result<Self, crate::Error> {
+        if nanos >= 1_000_000_000 {
+            return Err(crate::Error::InvalidDate);
+        }
+        let DateTime { date, mut time } = DateTime::<YmdDate, GlobalTime>::from_unix_seconds(secs)?;
+        time.local.fraction = nanos;
+        Ok(DateTime {
+            date: Date::YMD(date),
+            time,
+        })
+    }
+
+    #[inline]
+    fn shifted(&self, duration: &crate::Duration, sign: i8) -> Result<Self, crate::Error> {
+        let (date, local) = shift(self.date, &self.time.local, duration, sign)?;
+        Ok(DateTime {
+            date,
+            time: GlobalTime {
+                local,
+                timezone: self.time.timezone,
+            },
+        })
+    }
+}
+
+impl DateTime<Date, AnyTime> {
+    /// Adds a duration in local time,
+    /// like [`DateTime::<Date, GlobalTime>::checked_add`]
+    pub fn checked_add(&self, duration: &crate::Duration) -> Result<Self, crate::Error> {
+        self.shifted(duration, 1)
+    }
+
+    /// Subtracts a duration in local time,
+    /// like [`DateTime::<Date, GlobalTime>::checked_sub`]
+    pub fn checked_sub(&self, duration: &crate::Duration) -> Result<Self, crate::Error> {
+        self.shifted(duration, -1)
+    }
+
+    #[inline]
+    fn shifted(&self, duration: &crate::Duration, sign: i8) -> Result<Self, crate::Error> {
+        let local = match &self.time {
+            AnyTime::Global(time) => &time.local,
+            AnyTime::Local(time) => time,
+        };
+        let (date, local) = shift(self.date, local, duration, sign)?;
+        Ok(DateTime {
+            date,
+            time: match self.time {
+                AnyTime::Global(time) => AnyTime::Global(GlobalTime {
+                    local,
+                    timezone: time.timezone,
+                }),
+                AnyTime::Local(_) => AnyTime::Local(local),
+            },
+        })
+    }
+}
+
+impl<T: Timelike + EndOfDay> DateTime<Date, T> {
+    /// Rolls midnight at the end of the day, `24:00:00`, over to `00:00:00`
+    /// on the next day, keeping the date in the same representation.
+    /// Fails if the year of the next day does not fit.
+    pub fn normalize(self) -> Result<Self, crate::Error> {
+        match self.time.start_of_next_day() {
+            Some(time) => Ok(Self {
+                date: self.date.next_day().ok_or(crate::Error::InvalidDate)?,
+                time,
+            }),
+            None => Ok(self),
+        }
+    }
+}
+
 impl_fromstr_parse!(DateTime<Date,       GlobalTime<HmsTime>>, datetime_global_hms);
 impl_fromstr_parse!(DateTime<Date,       GlobalTime<HmTime>>,  datetime_global_hm);
 impl_fromstr_parse!(DateTime<Date,       GlobalTime<HTime>>,   datetime_global_h);
@@ -37,6 +312,8 @@ impl_fromstr_parse!(DateTime<ApproxDate, ApproxGlobalTime>,    datetime_approx_g
 impl_fromstr_parse!(DateTime<ApproxDate, ApproxLocalTime>,     datetime_approx_local_approx);
 impl_fromstr_parse!(DateTime<ApproxDate, ApproxAnyTime>,       datetime_approx_any_approx);
 
+impl_parse_strict!(DateTime<Date, AnyTime<HmsTime>>, datetime_strict);
+
 impl<D, T> Valid for DateTime<D, T>
 where
     D: Datelike + Valid,
@@ -48,7 +325,7 @@ where
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub enum PartialDateTime<D = ApproxDate, T = ApproxAnyTime>
 where
     D: Datelike,
@@ -77,3 +354,1293 @@ where
 }
 
 impl_fromstr_parse!(PartialDateTime<ApproxDate, ApproxAnyTime>, partial_datetime_approx_any_approx);
+impl_fromstr_parse!(PartialDateTime<Date, AnyTime<HmsTime>>, partial_datetime_any_hms);
+
+impl<D, T> WriteIso8601 for DateTime<D, T>
+where
+    D: Datelike + WriteIso8601,
+    T: Timelike + WriteIso8601,
+{
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        self.date.write_iso8601(w, extended)?;
+        w.write_char('T')?;
+        self.time.write_iso8601(w, extended)
+    }
+}
+
+impl<D, T> core::fmt::Display for DateTime<D, T>
+where
+    D: Datelike + core::fmt::Display,
+    T: Timelike + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.date.fmt(f)?;
+        f.write_str("T")?;
+        self.time.fmt(f)
+    }
+}
+
+/// Times are prefixed with `T` to tell them apart from dates.
+impl<D, T> WriteIso8601 for PartialDateTime<D, T>
+where
+    D: Datelike + WriteIso8601,
+    T: Timelike + WriteIso8601,
+{
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match self {
+            Self::Date(date) => date.write_iso8601(w, extended),
+            Self::Time(time) => {
+                w.write_char('T')?;
+                time.write_iso8601(w, extended)
+            }
+            Self::DateTime(datetime) => datetime.write_iso8601(w, extended),
+        }
+    }
+}
+
+impl<D, T> core::fmt::Display for PartialDateTime<D, T>
+where
+    D: Datelike + core::fmt::Display,
+    T: Timelike + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Date(date) => date.fmt(f),
+            Self::Time(time) => {
+                f.write_str("T")?;
+                time.fmt(f)
+            }
+            Self::DateTime(datetime) => datetime.fmt(f),
+        }
+    }
+}
+
+/// Parses a complete date, a time of full accuracy, or both,
+/// without falling back to reduced accuracy representations.
+#[inline]
+pub fn parse_date_or_datetime(
+    s: &str,
+) -> Result<PartialDateTime<Date, AnyTime<HmsTime>>, crate::Error> {
+    s.parse()
+}
+
+/// Parses any supported representation: a date, a time or both,
+/// of any accuracy. The whole input must be consumed.
+pub fn parse(s: &str) -> Result<PartialDateTime<ApproxDate, ApproxAnyTime>, crate::Error> {
+    use crate::Valid;
+
+    let res = nom::combinator::all_consuming(crate::parse::partial_datetime_approx_any_approx)(
+        s.as_bytes(),
+    )
+    .map(|x| x.1)
+    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))?;
+
+    res.is_valid()
+        .then_some(res)
+        .ok_or(crate::Error::InvalidDate)
+}
+
+/// Timezone field of [`DateTime::to_bytes`] standing for [`Timezone::Unknown`]
+const UNKNOWN_OFFSET: i16 = i16::MIN + 1;
+
+impl DateTime<YmdDate, GlobalTime<HmsTime>> {
+    /// Compact binary representation:
+    /// big-endian year, month, day, hour, minute, second,
+    /// nanosecond and timezone,
+    /// with `i16::MIN` standing for [`Timezone::Utc`]
+    /// and `i16::MIN + 1` for [`Timezone::Unknown`].
+    pub fn to_bytes(&self) -> [u8; 15] {
+        let local = &self.time.local;
+        let mut bytes = [0; 15];
+        bytes[0..4].copy_from_slice(&self.date.year.to_be_bytes());
+        bytes[4] = self.date.month;
+        bytes[5] = self.date.day;
+        bytes[6] = local.naive.hour;
+        bytes[7] = local.naive.minute;
+        bytes[8] = local.naive.second;
+        bytes[9..13].copy_from_slice(&local.fraction.to_be_bytes());
+        let timezone = match self.time.timezone {
+            Timezone::Utc => i16::MIN,
+            Timezone::Unknown => UNKNOWN_OFFSET,
+            Timezone::Fixed(minutes) => minutes,
+        };
+        bytes[13..15].copy_from_slice(&timezone.to_be_bytes());
+        bytes
+    }
+
+    /// Inverse of [`to_bytes`](Self::to_bytes)
+    pub fn from_bytes(bytes: [u8; 15]) -> Result<Self, crate::Error> {
+        let datetime = DateTime {
+            date: YmdDate {
+                year: i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                month: bytes[4],
+                day: bytes[5],
+            },
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: bytes[6],
+                        minute: bytes[7],
+                        second: bytes[8],
+                    },
+                    fraction: u32::from_be_bytes([bytes[9], bytes[10], bytes[11], bytes[12]]),
+                    fraction_digits: 0,
+                },
+                timezone: match i16::from_be_bytes([bytes[13], bytes[14]]) {
+                    i16::MIN => Timezone::Utc,
+                    UNKNOWN_OFFSET => Timezone::Unknown,
+                    minutes => Timezone::Fixed(minutes),
+                },
+            },
+        };
+
+        datetime.validated()
+    }
+
+    /// Parses the hexadecimal form written by the
+    /// [`LowerHex`](core::fmt::LowerHex) and [`UpperHex`](core::fmt::UpperHex) impls.
+    pub fn from_hex(s: &str) -> Result<Self, crate::Error> {
+        let s = s.as_bytes();
+        let mut bytes = [0; 15];
+        if s.len() != bytes.len() * 2 {
+            return Err(crate::Error::InvalidFormat {
+                offset: Some(s.len().min(bytes.len() * 2)),
+            });
+        }
+        for (i, (byte, digits)) in bytes.iter_mut().zip(s.chunks(2)).enumerate() {
+            let invalid = crate::Error::InvalidFormat {
+                offset: Some(i * 2),
+            };
+            let digits = core::str::from_utf8(digits).or(Err(invalid))?;
+            *byte = u8::from_str_radix(digits, 16).or(Err(invalid))?;
+        }
+        Self::from_bytes(bytes)
+    }
+
+    #[inline]
+    fn validated(self) -> Result<Self, crate::Error> {
+        if self.time.local.fraction >= 1_000_000_000 {
+            return Err(crate::Error::InvalidFraction);
+        }
+        self.is_valid()
+            .then_some(self)
+            .ok_or(crate::Error::InvalidDate)
+    }
+
+    /// UTC datetime `seconds` after the Unix epoch,
+    /// or [`Error::InvalidDate`](crate::Error::InvalidDate) if the year does not fit.
+    pub fn from_unix_seconds(seconds: i64) -> Result<Self, crate::Error> {
+        let day_seconds = seconds.rem_euclid(86_400);
+
+        Ok(DateTime {
+            date: YmdDate::from_days_since_epoch(seconds.div_euclid(86_400))
+                .ok_or(crate::Error::InvalidDate)?,
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: (day_seconds / 3_600) as u8,
+                        minute: (day_seconds / 60 % 60) as u8,
+                        second: (day_seconds % 60) as u8,
+                    },
+                    fraction: 0,
+                    fraction_digits: 0,
+                },
+                timezone: Timezone::Utc,
+            },
+        })
+    }
+
+    /// Whole seconds since the Unix epoch, ignoring the decimal fraction
+    #[inline]
+    pub(crate) fn unix_seconds(&self) -> i64 {
+        let naive = &self.time.local.naive;
+        self.date.days_since_epoch() * 86_400
+            + naive.hour as i64 * 3_600
+            + naive.minute as i64 * 60
+            + naive.second as i64
+            - self.time.timezone.minutes() as i64 * 60
+    }
+
+    /// Time elapsed since this datetime,
+    /// or [`Error::InvalidDate`](crate::Error::InvalidDate) if it lies in the future.
+    #[cfg(feature = "std")]
+    pub fn elapsed(&self) -> Result<std::time::Duration, crate::Error> {
+        const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .or(Err(crate::Error::InvalidDate))?
+            .as_nanos() as i128;
+        let then =
+            self.unix_seconds() as i128 * NANOS_PER_SEC + self.time.local.nanosecond() as i128;
+
+        let elapsed = now - then;
+        if elapsed < 0 {
+            return Err(crate::Error::InvalidDate);
+        }
+        Ok(std::time::Duration::new(
+            (elapsed / NANOS_PER_SEC) as u64,
+            (elapsed % NANOS_PER_SEC) as u32,
+        ))
+    }
+}
+
+/// Converts a Julian Date to a UTC datetime, to the nearest millisecond.
+impl TryFrom<f64> for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    type Error = crate::Error;
+
+    fn try_from(jd: f64) -> Result<Self, Self::Error> {
+        const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+        const MILLIS_PER_DAY: f64 = 86_400_000.;
+
+        let millis = crate::round((jd - UNIX_EPOCH_JD) * MILLIS_PER_DAY);
+        if !millis.is_finite() || millis.abs() > i64::MAX as f64 / 2. {
+            return Err(crate::Error::InvalidDate);
+        }
+        let millis = millis as i64;
+
+        let mut datetime = Self::from_unix_seconds(millis.div_euclid(1_000))?;
+        datetime.time.local.fraction = millis.rem_euclid(1_000) as u32 * 1_000_000;
+        Ok(datetime)
+    }
+}
+
+/// Like the system clock, ignores leap seconds:
+/// second 60 is the same instant as the start of the next minute.
+/// Fails for invalid dates and times, and out of the range of [`SystemTime`](std::time::SystemTime).
+#[cfg(feature = "std")]
+impl TryFrom<DateTime<Date, GlobalTime>> for std::time::SystemTime {
+    type Error = crate::Error;
+
+    fn try_from(dt: DateTime<Date, GlobalTime>) -> Result<Self, Self::Error> {
+        if !dt.is_valid() {
+            return Err(crate::Error::InvalidDate);
+        }
+        let nanos = dt.timestamp_nanos();
+        let since = std::time::Duration::new(
+            (nanos.unsigned_abs() / 1_000_000_000) as u64,
+            (nanos.unsigned_abs() % 1_000_000_000) as u32,
+        );
+        if nanos >= 0 {
+            std::time::UNIX_EPOCH.checked_add(since)
+        } else {
+            std::time::UNIX_EPOCH.checked_sub(since)
+        }
+        .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+/// The timezone is written as `Z`.
+/// Fails if the year does not fit.
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for DateTime<Date, GlobalTime<HmsTime>> {
+    type Error = crate::Error;
+
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let (secs, nanos) = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since) => (i64::try_from(since.as_secs()).ok(), since.subsec_nanos()),
+            Err(err) => {
+                let until = err.duration();
+                let secs = i64::try_from(until.as_secs())
+                    .ok()
+                    .and_then(i64::checked_neg);
+                match until.subsec_nanos() {
+                    0 => (secs, 0),
+                    nanos => (
+                        secs.and_then(|secs| secs.checked_sub(1)),
+                        1_000_000_000 - nanos,
+                    ),
+                }
+            }
+        };
+        Self::from_timestamp(secs.ok_or(crate::Error::InvalidDate)?, nanos)
+    }
+}
+
+impl core::fmt::LowerHex for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.to_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl core::fmt::UpperHex for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.to_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{:02X}", byte))
+    }
+}
+
+/// Orders by the instant in time, then by timezone, then by the
+/// written date and time, so that `24:00` and the `00:00` of the next day,
+/// or a leap second and the following second, compare equal only if
+/// they are written the same.
+impl Ord for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.unix_seconds()
+            .cmp(&other.unix_seconds())
+            .then(self.time.local.fraction.cmp(&other.time.local.fraction))
+            .then(self.time.timezone.cmp(&other.time.timezone))
+            .then(self.date.cmp(&other.date))
+            .then(self.time.local.cmp(&other.time.local))
+    }
+}
+
+impl PartialOrd for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Scans `text` for embedded global datetimes,
+/// yielding each matched slice along with its parsed value.
+///
+/// Bytes that do not start a valid datetime are skipped one at a time.
+pub fn find_datetimes(
+    text: &[u8],
+) -> impl Iterator<Item = (&[u8], DateTime<Date, GlobalTime<HmsTime>>)> {
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        while i < text.len() {
+            let input = &text[i..];
+            match crate::parse::datetime_global_hms(input) {
+                Ok((rest, datetime)) if datetime.is_valid() => {
+                    let len = input.len() - rest.len();
+                    i += len;
+                    return Some((&input[..len], datetime));
+                }
+                _ => i += 1,
+            }
+        }
+        None
+    })
+}
+
+/// Accumulates optional date and time components
+/// and assembles them into a global calendar datetime.
+///
+/// The year, month and day are required.
+/// Missing time components default to zero
+/// and a missing timezone defaults to UTC.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct DateTimeBuilder {
+    year: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    hour: Option<u8>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    fraction: Option<u32>,
+    timezone: Option<Timezone>,
+}
+
+impl DateTimeBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn year(self, year: i32) -> Self {
+        Self {
+            year: Some(year),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn month(self, month: u8) -> Self {
+        Self {
+            month: Some(month),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn hour(self, hour: u8) -> Self {
+        Self {
+            hour: Some(hour),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn minute(self, minute: u8) -> Self {
+        Self {
+            minute: Some(minute),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn second(self, second: u8) -> Self {
+        Self {
+            second: Some(second),
+            ..self
+        }
+    }
+
+    /// Decimal fraction of the second in billionths, so nanoseconds
+    #[inline]
+    pub fn fraction(self, fraction: u32) -> Self {
+        Self {
+            fraction: Some(fraction),
+            ..self
+        }
+    }
+
+    /// Difference from UTC in minutes
+    #[inline]
+    pub fn timezone(self, timezone: i16) -> Self {
+        Self {
+            timezone: Some(Timezone::Fixed(timezone)),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<DateTime<YmdDate, GlobalTime<HmsTime>>, crate::Error> {
+        let (year, month, day) = match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => (year, month, day),
+            _ => return Err(crate::Error::InvalidFormat { offset: None }),
+        };
+
+        let datetime = DateTime {
+            date: YmdDate { year, month, day },
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: self.hour.unwrap_or(0),
+                        minute: self.minute.unwrap_or(0),
+                        second: self.second.unwrap_or(0),
+                    },
+                    fraction: self.fraction.unwrap_or(0),
+                    fraction_digits: 0,
+                },
+                timezone: self.timezone.unwrap_or_default(),
+            },
+        };
+
+        datetime.validated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_datetimes() {
+        let text = b"Released on 2024-07-04T16:00:00Z after a delay. \
+            The hotfix followed at 20240705T083015+0200, see the notes.";
+        let found: Vec<_> = super::find_datetimes(text).collect();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, b"2024-07-04T16:00:00Z");
+        assert_eq!(
+            found[0].1,
+            DateTime {
+                date: Date::YMD(YmdDate {
+                    year: 2024,
+                    month: 7,
+                    day: 4,
+                }),
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 16,
+                            minute: 0,
+                            second: 0,
+                        },
+                        fraction: 0,
+                        fraction_digits: 0,
+                    },
+                    timezone: Timezone::Utc,
+                },
+            }
+        );
+        assert_eq!(found[1].0, b"20240705T083015+0200");
+        assert_eq!(
+            found[1].1,
+            DateTime {
+                date: Date::YMD(YmdDate {
+                    year: 2024,
+                    month: 7,
+                    day: 5,
+                }),
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 8,
+                            minute: 30,
+                            second: 15,
+                        },
+                        fraction: 0,
+                        fraction_digits: 0,
+                    },
+                    timezone: Timezone::Fixed(120),
+                },
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn elapsed() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            - 1;
+
+        let (mut year, mut day) = (1970i32, (secs / 86_400) as u16);
+        while day >= year.num_days() {
+            day -= year.num_days();
+            year += 1;
+        }
+        let seconds = secs % 86_400;
+
+        let datetime = DateTime {
+            date: YmdDate::from(ODate { year, day: day + 1 }),
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: (seconds / 3_600) as u8,
+                        minute: (seconds / 60 % 60) as u8,
+                        second: (seconds % 60) as u8,
+                    },
+                    fraction: 0,
+                    fraction_digits: 0,
+                },
+                timezone: Timezone::Utc,
+            },
+        };
+        assert!(datetime.elapsed().unwrap() >= Duration::from_secs(1));
+
+        let future = DateTimeBuilder::new()
+            .year(9999)
+            .month(12)
+            .day(31)
+            .build()
+            .unwrap();
+        assert!(matches!(future.elapsed(), Err(crate::Error::InvalidDate)));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            super::parse("2018-W31").unwrap(),
+            PartialDateTime::Date(ApproxDate::W(WDate {
+                year: 2018,
+                week: 31,
+            }))
+        );
+        assert_eq!(
+            super::parse("T16:43").unwrap(),
+            PartialDateTime::Time(ApproxAnyTime::HM(AnyTime::Local(LocalTime {
+                naive: HmTime {
+                    hour: 16,
+                    minute: 43,
+                },
+                fraction: 0,
+                fraction_digits: 0,
+            })))
+        );
+        assert!(matches!(
+            super::parse("2018-08-02T16:43:52+02:00"),
+            Ok(PartialDateTime::DateTime(DateTime {
+                date: ApproxDate::YMD(_),
+                time: ApproxAnyTime::HMS(AnyTime::Global(_)),
+            }))
+        ));
+        assert!(matches!(
+            super::parse(""),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            super::parse("2018-08-02 and more"),
+            Err(crate::Error::InvalidFormat { offset: Some(10) })
+        ));
+        assert!(matches!(
+            super::parse("2018-02-30T16:43"),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn checked_add() {
+        let add = |datetime: &str, duration: &str| {
+            datetime
+                .parse::<DateTime<Date, GlobalTime>>()
+                .unwrap()
+                .checked_add(&duration.parse().unwrap())
+        };
+        let assert_add = |datetime: &str, duration: &str, expected: &str| {
+            assert_eq!(add(datetime, duration).unwrap().to_string(), expected);
+        };
+
+        assert_add("2018-01-31T12:00:00Z", "P1M", "2018-02-28T12:00:00Z");
+        assert_add("2016-01-31T12:00:00Z", "P1M", "2016-02-29T12:00:00Z");
+        assert_add("2016-02-29T12:00:00Z", "P1Y", "2017-02-28T12:00:00Z");
+        assert_add("2016-02-29T12:00:00Z", "P4Y", "2020-02-29T12:00:00Z");
+        assert_add("2018-11-30T12:00:00Z", "P1Y3M", "2020-02-29T12:00:00Z");
+        assert_add("2018-01-31T12:00:00Z", "P1M1D", "2018-03-01T12:00:00Z");
+        assert_add("2016-02-28T12:00:00Z", "P1D", "2016-02-29T12:00:00Z");
+        assert_add("2018-08-02T13:42:05Z", "P2W", "2018-08-16T13:42:05Z");
+        assert_add(
+            "2018-12-31T23:00:00+02:00",
+            "PT2H",
+            "2019-01-01T01:00:00+02:00",
+        );
+        assert_add(
+            "2018-12-31T23:59:59.75Z",
+            "PT0.5S",
+            "2019-01-01T00:00:00.25Z",
+        );
+        assert_add("2018-08-02T13:42:05Z", "PT0.1S", "2018-08-02T13:42:05.1Z");
+        assert_add(
+            "2018-08-02T13:42:05Z",
+            "P1Y2M10DT2H30M",
+            "2019-10-12T16:12:05Z",
+        );
+        assert_add("2018-W52-7T12:00:00Z", "PT12H", "2019-W01-1T00:00:00Z");
+        assert_add("2018-365T12:00:00Z", "P1D", "2019-001T12:00:00Z");
+
+        assert!(matches!(
+            add("+2147483647-12-31T12:00:00Z", "PT12H"),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            add("+2147483647-01-01T12:00:00Z", "P1Y"),
+            Err(crate::Error::InvalidDate)
+        ));
+        let start = "2018-08-02T13:42:05Z"
+            .parse::<DateTime<Date, GlobalTime>>()
+            .unwrap();
+        assert_eq!(
+            start
+                .checked_add(&"P1M".parse().unwrap())
+                .and_then(|end| end.checked_sub(&"P1M".parse().unwrap()))
+                .unwrap(),
+            start
+        );
+        for duration in [
+            crate::Duration {
+                fraction: 1_000_000_000,
+                ..Default::default()
+            },
+            crate::Duration {
+                years: u32::MAX,
+                months: u32::MAX,
+                days: u32::MAX,
+                hours: u32::MAX,
+                minutes: u32::MAX,
+                seconds: u32::MAX,
+                fraction: 999_999_999,
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                start.checked_add(&duration),
+                Err(crate::Error::InvalidDate)
+            ));
+            assert!(matches!(
+                start.checked_sub(&duration),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+        let duration = crate::Duration {
+            days: u32::MAX,
+            hours: u32::MAX,
+            minutes: u32::MAX,
+            seconds: u32::MAX,
+            fraction: 999_999_999,
+            ..Default::default()
+        };
+        assert_eq!(
+            start
+                .checked_add(&duration)
+                .and_then(|end| end.checked_sub(&duration))
+                .unwrap(),
+            start
+        );
+    }
+
+    #[test]
+    fn checked_sub() {
+        let sub = |datetime: &str, duration: &str| {
+            datetime
+                .parse::<DateTime<Date, AnyTime>>()
+                .unwrap()
+                .checked_sub(&duration.parse().unwrap())
+        };
+        let assert_sub = |datetime: &str, duration: &str, expected: &str| {
+            assert_eq!(sub(datetime, duration).unwrap().to_string(), expected);
+        };
+
+        assert_sub("2018-03-31T12:00:00", "P1M", "2018-02-28T12:00:00");
+        assert_sub("2017-02-28T12:00:00Z", "P1Y", "2016-02-28T12:00:00Z");
+        assert_sub("2019-01-01T00:00:00.25", "PT0.5S", "2018-12-31T23:59:59.75");
+        assert_sub(
+            "2008-05-11T15:30:00Z",
+            "P1Y2M10DT2H30M",
+            "2007-03-01T13:00:00Z",
+        );
+        assert_sub("2019-001T12:00:00Z", "P1D", "2018-365T12:00:00Z");
+        assert!(matches!(
+            sub("-2147483648-01-01T00:00:00", "PT1S"),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn normalize() {
+        let normalize = |s: &str| {
+            s.parse::<DateTime<Date, AnyTime>>()
+                .unwrap()
+                .normalize()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(normalize("2018-12-31T24:00:00"), "2019-01-01T00:00:00");
+        assert_eq!(normalize("2016-02-28T24:00:00Z"), "2016-02-29T00:00:00Z");
+        assert_eq!(normalize("2018-W52-7T24:00:00"), "2019-W01-1T00:00:00");
+        assert_eq!(
+            normalize("2016-366T24:00:00+02:00"),
+            "2017-001T00:00:00+02:00"
+        );
+        assert_eq!(normalize("2018-12-31T23:59:59"), "2018-12-31T23:59:59");
+        assert!(matches!(
+            "+2147483647-12-31T24:00:00"
+                .parse::<DateTime<Date, AnyTime>>()
+                .unwrap()
+                .normalize(),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn partial_cmp() {
+        let parse = |s: &str| {
+            let datetime: DateTime<Date, GlobalTime<HmsTime>> = s.parse().unwrap();
+            DateTime {
+                date: YmdDate::from(datetime.date),
+                time: datetime.time,
+            }
+        };
+
+        // 22:59:59 UTC and 22:00:00 UTC on the same day
+        assert!(parse("2024-07-04T23:59:59+01:00") > parse("2024-07-05T00:00:00+02:00"));
+        assert!(parse("2024-07-04T23:59:59Z") < parse("2024-07-05T00:00:00Z"));
+        assert!(parse("2024-07-04T12:00:00.5Z") > parse("2024-07-04T12:00:00.25Z"));
+        assert!(parse("2024-07-04T13:00:00+01:00") > parse("2024-07-04T12:00:00Z"));
+        assert_eq!(
+            parse("2024-07-04T12:00:00Z").partial_cmp(&parse("2024-07-04T12:00:00Z")),
+            Some(core::cmp::Ordering::Equal)
+        );
+
+        // same instants, written differently
+        for (a, b) in [
+            ("2016-02-29T24:00:00Z", "2016-03-01T00:00:00Z"),
+            ("2016-12-31T23:59:60Z", "2017-01-01T00:00:00Z"),
+        ] {
+            let (a, b) = (parse(a), parse(b));
+            assert_ne!(a, b);
+            assert_ne!(a.cmp(&b), core::cmp::Ordering::Equal);
+            assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        }
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        for (i, s) in [
+            "2024-07-04T12:00:00Z",
+            "2024-07-04T13:00:00+01:00",
+            "2024-186T12:00:00Z",
+            "20240704T120000Z",
+            "2024-07-04T12:00:00.000Z",
+        ]
+        .iter()
+        .enumerate()
+        {
+            map.insert(s.parse::<DateTime<Date, GlobalTime>>().unwrap(), i);
+        }
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&"2024-07-04T12:00:00Z".parse().unwrap()], 4);
+
+        let mut map = HashMap::new();
+        for s in ["2024-07-04T12:00", "2024-07T12", "2024-07-04T12:00:00"] {
+            map.insert(s.parse::<PartialDateTime>().unwrap(), s);
+        }
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn hex() {
+        for s in [
+            "2024-07-04T16:00:00Z",
+            "1985-04-12T23:20:50.52+01:00",
+            "-0333-06-11T00:00:00-05:30",
+            "2016-02-29T24:00:00Z",
+            "2016-02-29T24:00:00+00:00",
+            "2016-02-29T12:00:00-00:00",
+        ] {
+            let datetime: DateTime<Date, GlobalTime<HmsTime>> = s.parse().unwrap();
+            let datetime = DateTime {
+                date: YmdDate::from(datetime.date),
+                time: datetime.time,
+            };
+
+            let lower = format!("{:x}", datetime);
+            let upper = format!("{:X}", datetime);
+            assert_eq!(lower.len(), 30);
+            assert_eq!(lower.to_uppercase(), upper);
+            assert_eq!(DateTime::from_hex(&lower).unwrap(), datetime);
+            assert_eq!(DateTime::from_hex(&upper).unwrap(), datetime);
+        }
+
+        assert_eq!(
+            format!(
+                "{:x}",
+                DateTimeBuilder::new()
+                    .year(2024)
+                    .month(7)
+                    .day(4)
+                    .hour(16)
+                    .timezone(-60)
+                    .build()
+                    .unwrap()
+            ),
+            "000007e8070410000000000000ffc4"
+        );
+        assert!(matches!(
+            DateTime::from_hex("000007e8070410000000000000ffc"),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            DateTime::from_hex("000007e80704100000000000zzffc4"),
+            Err(crate::Error::InvalidFormat { offset: Some(24) })
+        ));
+        assert!(matches!(
+            DateTime::from_hex("000007e80d0410000000000000ffc4"),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            DateTime::from_hex("000007e807041000003b9aca00ffc4"),
+            Err(crate::Error::InvalidFraction)
+        ));
+    }
+
+    #[test]
+    fn timelike() {
+        let datetime: DateTime<Date, GlobalTime<HmTime>> =
+            "2024-07-04T16:43.5+02:00".parse().unwrap();
+        assert_eq!(
+            (
+                datetime.hour(),
+                datetime.minute(),
+                datetime.second(),
+                datetime.nanosecond()
+            ),
+            (16, 43, 30, 0)
+        );
+        assert_eq!(
+            (datetime.year(), datetime.month(), datetime.day()),
+            (2024, Some(7), Some(4))
+        );
+        assert_eq!(datetime.offset_minutes(), Some(120));
+    }
+
+    #[test]
+    fn julian_date() {
+        let utc = |year, month, day, hour| {
+            DateTimeBuilder::new()
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(hour)
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(DateTime::try_from(2_451_545.).unwrap(), utc(2000, 1, 1, 12));
+        assert_eq!(DateTime::try_from(2_440_587.5).unwrap(), utc(1970, 1, 1, 0));
+        assert_eq!(DateTime::try_from(-1.).unwrap(), utc(-4713, 11, 23, 12));
+
+        let datetime = DateTime::try_from(2_451_545.25 + 0.5 / 86_400.).unwrap();
+        assert_eq!(
+            datetime.time.local,
+            LocalTime {
+                naive: HmsTime {
+                    hour: 18,
+                    minute: 0,
+                    second: 0,
+                },
+                fraction: 500_000_000,
+                fraction_digits: 0,
+            }
+        );
+
+        for jd in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, 1e12] {
+            assert!(matches!(
+                DateTime::try_from(jd),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+    }
+
+    #[test]
+    fn unix_seconds() {
+        let utc = |year, month, day, hour, minute, second| {
+            DateTimeBuilder::new()
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(hour)
+                .minute(minute)
+                .second(second)
+                .build()
+                .unwrap()
+        };
+
+        for (seconds, datetime) in [
+            (0, utc(1970, 1, 1, 0, 0, 0)),
+            (951_782_400, utc(2000, 2, 29, 0, 0, 0)),
+            (1_700_000_000, utc(2023, 11, 14, 22, 13, 20)),
+            (-1, utc(1969, 12, 31, 23, 59, 59)),
+        ] {
+            assert_eq!(DateTime::from_unix_seconds(seconds).unwrap(), datetime);
+            assert_eq!(datetime.unix_seconds(), seconds);
+        }
+
+        assert!(matches!(
+            DateTime::from_unix_seconds(i64::MAX),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn signed_duration_since() {
+        let parse = |s: &str| s.parse::<DateTime<Date, GlobalTime>>().unwrap();
+        let since = |a: &str, b: &str| {
+            let (a, b) = (parse(a), parse(b));
+            let (secs, nanos) = a.signed_duration_since(&b);
+            assert_eq!(b.signed_duration_since(&a), (-secs, -nanos));
+            (secs, nanos)
+        };
+
+        assert_eq!(
+            since("2018-08-02T13:42:00Z", "2018-08-02T13:42:00+00:00"),
+            (0, 0)
+        );
+        assert_eq!(
+            since("2018-03-01T00:00:00Z", "2018-02-28T23:59:59.75Z"),
+            (0, 250_000_000)
+        );
+        assert_eq!(
+            since("2016-03-01T00:00:00Z", "2016-02-01T00:00:00Z"),
+            (29 * 86_400, 0)
+        );
+        assert_eq!(
+            since("2018-08-02T13:42:00+02:00", "2018-08-02T13:42:00-05:00"),
+            (-7 * 3_600, 0)
+        );
+        assert_eq!(
+            since("2019-01-01T01:00:00+02:00", "2018-12-31T18:00:00-05:00"),
+            (0, 0)
+        );
+        assert_eq!(
+            since("2018-W31-4T12:00:00Z", "2018-214T00:00:00.5+01:00"),
+            (46_799, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        let parse = |s: &str| s.parse::<DateTime<Date, GlobalTime>>().unwrap();
+
+        let epoch = parse("1970-01-01T00:00:00Z");
+        assert_eq!(epoch.timestamp(), 0);
+        assert_eq!(epoch.timestamp_nanos(), 0);
+        assert_eq!(DateTime::from_timestamp(0, 0).unwrap(), epoch);
+
+        let moon = parse("1969-07-20T20:17:40.25Z");
+        assert_eq!(moon.timestamp(), -14_182_940);
+        assert_eq!(moon.timestamp_nanos(), -14_182_939_750_000_000);
+        assert_eq!(
+            DateTime::from_timestamp(-14_182_940, 250_000_000).unwrap(),
+            moon
+        );
+
+        let offset = parse("2018-08-02T13:42:05.5+02:00");
+        assert_eq!(offset.timestamp(), 1_533_210_125);
+        assert_eq!(offset.timestamp_nanos(), 1_533_210_125_500_000_000);
+        assert_eq!(
+            DateTime::from_timestamp(1_533_210_125, 500_000_000)
+                .unwrap()
+                .to_string(),
+            "2018-08-02T11:42:05.5Z"
+        );
+        assert_eq!(parse("1900-001T00:00:00Z").timestamp(), -2_208_988_800);
+
+        assert!(matches!(
+            DateTime::from_timestamp(0, 1_000_000_000),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            DateTime::from_timestamp(i64::MIN, 0),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn system_time() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let parse = |s: &str| s.parse::<DateTime<Date, GlobalTime>>().unwrap();
+
+        for (s, time) in [
+            ("1970-01-01T00:00:00Z", UNIX_EPOCH),
+            (
+                "2018-08-02T11:42:05.5Z",
+                UNIX_EPOCH + Duration::new(1_533_210_125, 500_000_000),
+            ),
+            (
+                "1969-07-20T20:17:40.25Z",
+                UNIX_EPOCH - Duration::new(14_182_939, 750_000_000),
+            ),
+        ] {
+            assert_eq!(SystemTime::try_from(parse(s)).unwrap(), time, "{}", s);
+            assert_eq!(DateTime::try_from(time).unwrap().to_string(), s);
+        }
+
+        assert_eq!(
+            SystemTime::try_from(parse("2018-W31-4T13:42:05.5+02:00")).unwrap(),
+            UNIX_EPOCH + Duration::new(1_533_210_125, 500_000_000)
+        );
+        assert_eq!(
+            SystemTime::try_from(parse("2016-12-31T23:59:60Z")).unwrap(),
+            SystemTime::try_from(parse("2017-01-01T00:00:00Z")).unwrap()
+        );
+        let invalid = DateTime {
+            date: Date::O(ODate {
+                year: 2017,
+                day: 366,
+            }),
+            ..parse("2017-01-01T00:00:00Z")
+        };
+        assert!(matches!(
+            SystemTime::try_from(invalid),
+            Err(crate::Error::InvalidDate)
+        ));
+
+        let now = SystemTime::now();
+        assert_eq!(
+            SystemTime::try_from(DateTime::try_from(now).unwrap()).unwrap(),
+            now
+        );
+        assert!(matches!(
+            DateTime::try_from(UNIX_EPOCH + Duration::from_secs(i64::MAX as u64 / 2)),
+            Err(crate::Error::InvalidDate)
+        ));
+        // the extremes of the platform, where the seconds may not fit an `i64`
+        for time in [
+            UNIX_EPOCH.checked_add(Duration::new(u64::MAX, 0)),
+            UNIX_EPOCH.checked_add(Duration::new(i64::MAX as u64, 999_999_999)),
+            UNIX_EPOCH.checked_sub(Duration::new(u64::MAX, 0)),
+            UNIX_EPOCH.checked_sub(Duration::new(i64::MAX as u64 + 1, 0)),
+            UNIX_EPOCH.checked_sub(Duration::new(i64::MAX as u64, 500_000_000)),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            assert!(matches!(
+                DateTime::try_from(time),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+    }
+
+    #[test]
+    fn as_ymd() {
+        let ymd = YmdDate {
+            year: 2018,
+            month: 8,
+            day: 2,
+        };
+        for s in [
+            "2018-08-02T13:42:00Z",
+            "2018-W31-4T13:42:00Z",
+            "2018-214T13:42:00Z",
+        ] {
+            let datetime: DateTime<Date, GlobalTime> = s.parse().unwrap();
+            assert_eq!(datetime.as_ymd(), ymd);
+        }
+    }
+
+    #[test]
+    fn with_date() {
+        let datetime: DateTime<Date, GlobalTime> = "2018-08-02T13:42:00Z".parse().unwrap();
+        let date = Date::O(ODate { year: 2019, day: 1 });
+        let replaced = datetime.with_date(date);
+        assert_eq!(replaced.date, date);
+        assert_eq!(replaced.time, datetime.time);
+    }
+
+    fn round_trip<T>(s: &str, expected: &str)
+    where
+        T: core::str::FromStr<Err = crate::Error>
+            + core::fmt::Display
+            + PartialEq
+            + core::fmt::Debug,
+    {
+        let datetime: T = s.parse().unwrap();
+        assert_eq!(datetime.to_string(), expected);
+        assert_eq!(datetime.to_string().parse::<T>().unwrap(), datetime);
+    }
+
+    #[test]
+    fn display() {
+        round_trip::<DateTime<Date, GlobalTime<HmsTime>>>(
+            "2018-08-02T12:30:15.2Z",
+            "2018-08-02T12:30:15.2Z",
+        );
+        round_trip::<DateTime<Date, GlobalTime<HmsTime>>>(
+            "20180802T123015+0200",
+            "2018-08-02T12:30:15+02:00",
+        );
+        round_trip::<DateTime<Date, GlobalTime<HmTime>>>(
+            "2018-W31-4T12:30-01:00",
+            "2018-W31-4T12:30-01:00",
+        );
+        round_trip::<DateTime<Date, GlobalTime<HTime>>>("2018-214T12Z", "2018-214T12Z");
+        round_trip::<DateTime<Date, LocalTime<HmsTime>>>(
+            "2018-08-02T12:30:15.25",
+            "2018-08-02T12:30:15.25",
+        );
+        round_trip::<DateTime<Date, LocalTime<HmTime>>>("2018-08-02T12:30", "2018-08-02T12:30");
+        round_trip::<DateTime<Date, LocalTime<HTime>>>("2018-08-02T12", "2018-08-02T12");
+        round_trip::<DateTime<Date, AnyTime<HmsTime>>>(
+            "2018-08-02T12:30:15",
+            "2018-08-02T12:30:15",
+        );
+        round_trip::<DateTime<Date, AnyTime<HmTime>>>("2018-08-02T12:30Z", "2018-08-02T12:30Z");
+        round_trip::<DateTime<Date, AnyTime<HTime>>>("2018-08-02T12", "2018-08-02T12");
+        round_trip::<DateTime<Date, ApproxGlobalTime>>("2018-08-02T12Z", "2018-08-02T12Z");
+        round_trip::<DateTime<Date, ApproxLocalTime>>("2018-08-02T12:30", "2018-08-02T12:30");
+        round_trip::<DateTime<Date, ApproxAnyTime>>(
+            "2018-08-02T12:30:15.5",
+            "2018-08-02T12:30:15.5",
+        );
+        round_trip::<DateTime<ApproxDate, GlobalTime<HmsTime>>>(
+            "2018-08T12:30:15Z",
+            "2018-08T12:30:15Z",
+        );
+        round_trip::<DateTime<ApproxDate, GlobalTime<HmTime>>>("2018T12:30Z", "2018T12:30Z");
+        round_trip::<DateTime<ApproxDate, GlobalTime<HTime>>>("2018-W31T12Z", "2018-W31T12Z");
+        round_trip::<DateTime<ApproxDate, LocalTime<HmsTime>>>(
+            "2018-08-02T12:30:15",
+            "2018-08-02T12:30:15",
+        );
+        round_trip::<DateTime<ApproxDate, LocalTime<HmTime>>>("2018-08T12:30", "2018-08T12:30");
+        round_trip::<DateTime<ApproxDate, LocalTime<HTime>>>("2018-214T12", "2018-214T12");
+        round_trip::<DateTime<ApproxDate, AnyTime<HmsTime>>>(
+            "2018-08T12:30:15+05:30",
+            "2018-08T12:30:15+05:30",
+        );
+        round_trip::<DateTime<ApproxDate, AnyTime<HmTime>>>("2018-08T12:30", "2018-08T12:30");
+        round_trip::<DateTime<ApproxDate, AnyTime<HTime>>>("2018-08T12Z", "2018-08T12Z");
+        round_trip::<DateTime<ApproxDate, ApproxGlobalTime>>("2018-08T12:30Z", "2018-08T12:30Z");
+        round_trip::<DateTime<ApproxDate, ApproxLocalTime>>("2018-08T12.5", "2018-08T12.5");
+        round_trip::<DateTime<ApproxDate, ApproxAnyTime>>("2018-08T12:30", "2018-08T12:30");
+        round_trip::<PartialDateTime<ApproxDate, ApproxAnyTime>>("2018-08", "2018-08");
+        round_trip::<PartialDateTime<ApproxDate, ApproxAnyTime>>("T12", "T12");
+        round_trip::<PartialDateTime<ApproxDate, ApproxAnyTime>>("12:30Z", "T12:30Z");
+        round_trip::<PartialDateTime<ApproxDate, ApproxAnyTime>>("2018-08T12:30", "2018-08T12:30");
+        round_trip::<PartialDateTime<Date, AnyTime<HmsTime>>>("2018-08-02", "2018-08-02");
+        round_trip::<PartialDateTime<Date, AnyTime<HmsTime>>>("12:30:15", "T12:30:15");
+        round_trip::<PartialDateTime<Date, AnyTime<HmsTime>>>(
+            "2018-08-02T12:30:15Z",
+            "2018-08-02T12:30:15Z",
+        );
+    }
+
+    #[test]
+    fn builder() {
+        assert_eq!(
+            DateTimeBuilder::new()
+                .year(2024)
+                .month(7)
+                .day(4)
+                .hour(16)
+                .build()
+                .unwrap(),
+            DateTime {
+                date: YmdDate {
+                    year: 2024,
+                    month: 7,
+                    day: 4,
+                },
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 16,
+                            minute: 0,
+                            second: 0,
+                        },
+                        fraction: 0,
+                        fraction_digits: 0,
+                    },
+                    timezone: Timezone::Utc,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn builder_invalid() {
+        assert!(matches!(
+            DateTimeBuilder::new().year(2024).day(4).build(),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            DateTimeBuilder::new().year(2023).month(2).day(29).build(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            DateTimeBuilder::new()
+                .year(2024)
+                .month(7)
+                .day(4)
+                .timezone(24 * 60)
+                .build(),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            DateTimeBuilder::new()
+                .year(2024)
+                .month(7)
+                .day(4)
+                .fraction(1_000_000_000)
+                .build(),
+            Err(crate::Error::InvalidFraction)
+        ));
+    }
+}
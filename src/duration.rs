@@ -0,0 +1,344 @@
+use crate::{Valid, WriteIso8601};
+
+/// Duration in the format with designators (4.4.3.2)
+///
+/// The week form `PnW` cannot be combined with the other components:
+/// such a duration parses, but is rejected by [`Valid`].
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug, Default)]
+pub struct Duration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    /// Decimal fraction of the seconds, in billionths
+    pub fraction: u32,
+}
+
+impl_fromstr_parse!(Duration, duration);
+
+impl Duration {
+    /// The same duration with each week counted as 7 days,
+    /// or `None` on overflow.
+    ///
+    /// ```
+    /// # use iso_8601::Duration;
+    /// let duration: Duration = "P3W".parse().unwrap();
+    /// assert_eq!(duration.weeks_to_days().unwrap().to_string(), "P21D");
+    /// ```
+    pub fn weeks_to_days(&self) -> Option<Self> {
+        Some(Self {
+            weeks: 0,
+            days: self.weeks.checked_mul(7)?.checked_add(self.days)?,
+            ..*self
+        })
+    }
+
+    /// Every component multiplied by `n`, carrying whole minutes
+    /// out of the seconds, or `None` on overflow.
+    pub(crate) fn checked_mul(&self, n: u32) -> Option<Self> {
+        const NANOS_PER_SECOND: u128 = 1_000_000_000;
+        const NANOS_PER_MINUTE: u128 = 60 * NANOS_PER_SECOND;
+
+        let nanos = (self.seconds as u128 * NANOS_PER_SECOND + self.fraction as u128) * n as u128;
+        Some(Self {
+            years: self.years.checked_mul(n)?,
+            months: self.months.checked_mul(n)?,
+            weeks: self.weeks.checked_mul(n)?,
+            days: self.days.checked_mul(n)?,
+            hours: self.hours.checked_mul(n)?,
+            minutes: self
+                .minutes
+                .checked_mul(n)?
+                .checked_add((nanos / NANOS_PER_MINUTE).try_into().ok()?)?,
+            seconds: (nanos % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u32,
+            fraction: (nanos % NANOS_PER_SECOND) as u32,
+        })
+    }
+}
+
+impl Valid for Duration {
+    /// Weeks may not be combined with other components.
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.fraction < 1_000_000_000
+            && (self.weeks == 0 || Duration { weeks: 0, ..*self } == Duration::default())
+    }
+}
+
+/// Omits zero components, and writes `PT0S` for an empty duration.
+/// The format with designators has no basic form, so `extended` is ignored.
+impl WriteIso8601 for Duration {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, _extended: bool) -> core::fmt::Result {
+        w.write_str("P")?;
+        if *self == Duration::default() {
+            return w.write_str("T0S");
+        }
+
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(w, "{}{}", value, designator)?;
+            }
+        }
+
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.fraction != 0 {
+            w.write_str("T")?;
+            if self.hours != 0 {
+                write!(w, "{}H", self.hours)?;
+            }
+            if self.minutes != 0 {
+                write!(w, "{}M", self.minutes)?;
+            }
+            if self.seconds != 0 || self.fraction != 0 {
+                write!(w, "{}", self.seconds)?;
+                crate::time::write_fraction(w, self.fraction, 0)?;
+                w.write_str("S")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl_display_iso8601!(Duration);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "P1Y2M10DT2H30M".parse::<Duration>().unwrap(),
+            Duration {
+                years: 1,
+                months: 2,
+                days: 10,
+                hours: 2,
+                minutes: 30,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            "PT0.5S".parse::<Duration>().unwrap(),
+            Duration {
+                fraction: 500_000_000,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            "PT0,5S".parse::<Duration>().unwrap(),
+            "PT0.5S".parse::<Duration>().unwrap()
+        );
+        assert_eq!(
+            "PT16777217.123456789S".parse::<Duration>().unwrap(),
+            Duration {
+                seconds: 16_777_217,
+                fraction: 123_456_789,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            "P1W".parse::<Duration>().unwrap(),
+            Duration {
+                weeks: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!("P0D".parse::<Duration>().unwrap(), Duration::default());
+
+        for s in ["P", "PT", "P1YT", "1Y", "P1H", "PT1D", ""] {
+            assert!(
+                matches!(
+                    s.parse::<Duration>(),
+                    Err(crate::Error::InvalidFormat { .. })
+                ),
+                "{}",
+                s
+            );
+        }
+        assert!(matches!(
+            "P1W2D".parse::<Duration>(),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn valid() {
+        assert!(Duration::default().is_valid());
+        assert!(Duration {
+            weeks: 2,
+            ..Default::default()
+        }
+        .is_valid());
+        assert!(!Duration {
+            weeks: 2,
+            seconds: 1,
+            ..Default::default()
+        }
+        .is_valid());
+        assert!(!Duration {
+            fraction: 1_000_000_000,
+            ..Default::default()
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn weeks_to_days() {
+        assert_eq!(
+            Duration {
+                weeks: 3,
+                ..Default::default()
+            }
+            .weeks_to_days(),
+            Some(Duration {
+                days: 21,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            Duration {
+                weeks: 1,
+                days: 2,
+                hours: 5,
+                ..Default::default()
+            }
+            .weeks_to_days(),
+            Some(Duration {
+                days: 9,
+                hours: 5,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            Duration {
+                weeks: u32::MAX / 7 + 1,
+                ..Default::default()
+            }
+            .weeks_to_days(),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_mul() {
+        let duration = Duration {
+            days: 2,
+            seconds: 50,
+            fraction: 100_000_000,
+            ..Default::default()
+        };
+        assert_eq!(
+            duration.checked_mul(3),
+            Some(Duration {
+                days: 6,
+                minutes: 2,
+                seconds: 30,
+                fraction: 300_000_000,
+                ..Default::default()
+            })
+        );
+        assert_eq!(duration.checked_mul(u32::MAX), None);
+    }
+
+    #[test]
+    fn write_iso8601() {
+        for s in [
+            "PT0S",
+            "P2Y",
+            "P1M",
+            "PT1M",
+            "P4W",
+            "P1Y10D",
+            "P3MT4H",
+            "PT36H0.5S",
+            "P1DT1M1S",
+            "PT0.000000001S",
+            "P3Y6M4DT12H30M5.25S",
+        ] {
+            let duration: Duration = s.parse().unwrap();
+            for extended in [true, false] {
+                let mut buf = String::new();
+                duration.write_iso8601(&mut buf, extended).unwrap();
+                assert_eq!(buf, s);
+                assert_eq!(buf.parse::<Duration>().unwrap(), duration);
+            }
+            assert_eq!(format!("{:#}", duration), s);
+        }
+        for (s, expected) in [
+            ("P0Y0M", "PT0S"),
+            ("PT0H0.50S", "PT0.5S"),
+            ("P0DT0M1S", "PT1S"),
+        ] {
+            assert_eq!(s.parse::<Duration>().unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn display() {
+        for (duration, expected) in [
+            (Duration::default(), "PT0S"),
+            (
+                Duration {
+                    years: 1,
+                    ..Default::default()
+                },
+                "P1Y",
+            ),
+            (
+                Duration {
+                    weeks: 3,
+                    ..Default::default()
+                },
+                "P3W",
+            ),
+            (
+                Duration {
+                    months: 2,
+                    minutes: 5,
+                    ..Default::default()
+                },
+                "P2MT5M",
+            ),
+            (
+                Duration {
+                    days: 1,
+                    hours: 12,
+                    fraction: 250_000_000,
+                    ..Default::default()
+                },
+                "P1DT12H0.25S",
+            ),
+            (
+                Duration {
+                    seconds: 1,
+                    fraction: 123_456_789,
+                    ..Default::default()
+                },
+                "PT1.123456789S",
+            ),
+            (
+                Duration {
+                    years: 1,
+                    months: 2,
+                    days: 10,
+                    hours: 2,
+                    minutes: 30,
+                    seconds: 15,
+                    ..Default::default()
+                },
+                "P1Y2M10DT2H30M15S",
+            ),
+        ] {
+            assert_eq!(duration.to_string(), expected);
+            assert_eq!(expected.parse::<Duration>().unwrap(), duration);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "hifitime")]
+use {
+    crate::{Date, DateTime, GlobalTime, Valid},
+    hifitime::{
+        leap_seconds::{LatestLeapSeconds, LeapSecond},
+        Duration, Epoch,
+    },
+};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Seconds from 1900-01-01, the reference of hifitime, to the Unix epoch
+const J1900_TO_UNIX: i64 = 2_208_988_800;
+
+/// TAI − UTC in seconds after the latest leap second for which `reached` holds,
+/// or zero before 1972, when UTC started to differ from TAI by whole seconds
+#[inline]
+fn delta_at(reached: impl Fn(&LeapSecond, f64) -> bool) -> i64 {
+    LatestLeapSeconds::default()
+        .rev()
+        .find(|leap| leap.announced_by_iers && reached(leap, leap.timestamp_tai_s))
+        .map_or(0, |leap| leap.delta_at as i64)
+}
+
+/// TAI − UTC at a UTC second counted since 1900 without leap seconds
+#[inline]
+fn delta_at_utc(seconds: i64) -> i64 {
+    delta_at(|_, start| seconds as f64 >= start)
+}
+
+/// TAI − UTC at a TAI second counted since 1900
+#[inline]
+fn delta_at_tai(seconds: i64) -> i64 {
+    delta_at(|leap, start| seconds as f64 >= start + leap.delta_at)
+}
+
+/// Goes through TAI with the table of leap seconds, so that second 60
+/// gets an instant of its own. Fails for invalid dates and times,
+/// and for a second 60 at which no leap second was inserted.
+impl TryFrom<DateTime<Date, GlobalTime>> for Epoch {
+    type Error = crate::Error;
+
+    fn try_from(mut dt: DateTime<Date, GlobalTime>) -> Result<Self, Self::Error> {
+        if !dt.is_valid() {
+            return Err(crate::Error::InvalidDate);
+        }
+        let leap_second = dt.time.local.naive.second == 60;
+        if leap_second {
+            dt.time.local.naive.second = 59;
+        }
+
+        let utc = dt.timestamp() + J1900_TO_UNIX;
+        let mut tai = utc + delta_at_utc(utc);
+        if leap_second {
+            if delta_at_utc(utc + 1) != delta_at_utc(utc) + 1 {
+                return Err(crate::Error::InvalidDate);
+            }
+            tai += 1;
+        }
+
+        Ok(Epoch::from_tai_duration(Duration::from_total_nanoseconds(
+            tai as i128 * NANOS_PER_SECOND + dt.time.local.nanosecond() as i128,
+        )))
+    }
+}
+
+/// The epoch in UTC, with second 60 during a leap second.
+/// Fails if the year does not fit.
+impl TryFrom<Epoch> for DateTime<Date, GlobalTime> {
+    type Error = crate::Error;
+
+    fn try_from(epoch: Epoch) -> Result<Self, Self::Error> {
+        let nanos = epoch.to_tai_duration().total_nanoseconds();
+        let tai =
+            i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).or(Err(crate::Error::InvalidDate))?;
+        let fraction = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+
+        let delta = delta_at_tai(tai);
+        let utc = tai - delta;
+        // during a leap second, the UTC second already
+        // has the offset that TAI only reaches after it
+        let leap_second = delta_at_utc(utc) > delta;
+        let utc = if leap_second { utc - 1 } else { utc };
+
+        let mut dt = DateTime::<Date, GlobalTime>::from_timestamp(utc - J1900_TO_UNIX, fraction)?;
+        if leap_second {
+            dt.time.local.naive.second = 60;
+        }
+        Ok(dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> DateTime<Date, GlobalTime> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn leap_second() {
+        for (s, tai) in [
+            ("2016-12-31T23:59:59Z", (2017, 1, 1, 0, 0, 35, 0)),
+            ("2016-12-31T23:59:60Z", (2017, 1, 1, 0, 0, 36, 0)),
+            (
+                "2016-12-31T23:59:60.5Z",
+                (2017, 1, 1, 0, 0, 36, 500_000_000),
+            ),
+            ("2017-01-01T00:00:00Z", (2017, 1, 1, 0, 0, 37, 0)),
+            (
+                "2017-01-01T00:00:00.25Z",
+                (2017, 1, 1, 0, 0, 37, 250_000_000),
+            ),
+            ("2015-06-30T23:59:60Z", (2015, 7, 1, 0, 0, 35, 0)),
+            ("1972-01-01T00:00:00Z", (1972, 1, 1, 0, 0, 10, 0)),
+            ("1960-01-01T00:00:00Z", (1960, 1, 1, 0, 0, 0, 0)),
+        ] {
+            let epoch = Epoch::try_from(parse(s)).unwrap();
+            assert_eq!(epoch.to_gregorian_tai(), tai, "{}", s);
+            assert_eq!(DateTime::try_from(epoch).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        let utc = Epoch::try_from(parse("2016-12-31T23:59:60.5Z")).unwrap();
+        for s in [
+            "2017-01-01T05:29:60.5+05:30",
+            "2016-12-31T18:59:60.5-05:00",
+            "2016-366T23:59:60.5-00:00",
+        ] {
+            assert_eq!(Epoch::try_from(parse(s)).unwrap(), utc, "{}", s);
+        }
+
+        let epoch = Epoch::try_from(parse("2018-08-02T13:42:00.5+02:00")).unwrap();
+        assert_eq!(
+            DateTime::try_from(epoch).unwrap().to_string(),
+            "2018-08-02T11:42:00.5Z"
+        );
+        // away from leap seconds, hifitime agrees on UTC
+        assert_eq!(
+            epoch.to_gregorian_utc(),
+            (2018, 8, 2, 11, 42, 0, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [
+            "2017-06-30T23:59:60Z",
+            "2016-12-31T23:58:60Z",
+            "2016-12-30T23:59:60Z",
+        ] {
+            assert!(matches!(
+                Epoch::try_from(parse(s)),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+        let invalid = DateTime {
+            date: Date::O(crate::ODate {
+                year: 2017,
+                day: 366,
+            }),
+            ..parse("2017-01-01T00:00:00Z")
+        };
+        assert!(Epoch::try_from(invalid).is_err());
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{AnyTime, Date, DateTime, Duration, GlobalTime, Timezone, Valid};
+
+/// Time interval, with the start and end separated by a solidus (4.4.4)
+///
+/// An endpoint given as a date alone, like in `2020-01-01/P1M`,
+/// stands for midnight at the start of that day.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Interval {
+    /// `start/end`. An end omitting its higher order components,
+    /// like `2007-12-14T13:30/15:30`, inherits them from the start.
+    StartEnd(DateTime<Date, AnyTime>, DateTime<Date, AnyTime>),
+    /// `start/duration`
+    StartDuration(DateTime<Date, AnyTime>, Duration),
+    /// `duration/end`
+    DurationEnd(Duration, DateTime<Date, AnyTime>),
+}
+
+impl Interval {
+    /// The start, computed from the end and duration if needed
+    pub fn start(&self) -> Result<DateTime<Date, AnyTime>, crate::Error> {
+        match self {
+            Self::StartEnd(start, _) | Self::StartDuration(start, _) => Ok(*start),
+            Self::DurationEnd(duration, end) => end.checked_sub(duration),
+        }
+    }
+
+    /// The end, computed from the start and duration if needed
+    pub fn end(&self) -> Result<DateTime<Date, AnyTime>, crate::Error> {
+        match self {
+            Self::StartEnd(_, end) | Self::DurationEnd(_, end) => Ok(*end),
+            Self::StartDuration(start, duration) => start.checked_add(duration),
+        }
+    }
+}
+
+impl Interval {
+    /// Iterates over `start + n * step` for `n = 0, 1, ...` until the end,
+    /// which is included only if `inclusive` is set.
+    ///
+    /// Each instant is computed with [`checked_add`](DateTime::checked_add),
+    /// so a step of one month stays on the same day of the month when it can.
+    /// Fails with [`Error::InvalidDate`](crate::Error::InvalidDate)
+    /// if the step is zero or invalid, or if an endpoint cannot be computed.
+    pub fn iter_step(&self, step: Duration, inclusive: bool) -> Result<Steps, crate::Error> {
+        if step == Duration::default() || !step.is_valid() {
+            return Err(crate::Error::InvalidDate);
+        }
+        let start = self.start()?;
+        Ok(Steps {
+            start,
+            end: nanos(&self.end()?),
+            step,
+            inclusive,
+            index: Some(0),
+        })
+    }
+}
+
+impl_fromstr_parse!(Interval, interval);
+
+impl Valid for Interval {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::StartEnd(start, end) => start.is_valid() && end.is_valid(),
+            Self::StartDuration(start, duration) => start.is_valid() && duration.is_valid(),
+            Self::DurationEnd(duration, end) => duration.is_valid() && end.is_valid(),
+        }
+    }
+}
+
+/// Writes both endpoints in full, even if the end was abbreviated,
+/// in the basic format with the alternate flag `{:#}`.
+impl core::fmt::Display for Interval {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::StartEnd(start, end) => {
+                start.fmt(f)?;
+                f.write_str("/")?;
+                end.fmt(f)
+            }
+            Self::StartDuration(start, duration) => {
+                start.fmt(f)?;
+                f.write_str("/")?;
+                duration.fmt(f)
+            }
+            Self::DurationEnd(duration, end) => {
+                duration.fmt(f)?;
+                f.write_str("/")?;
+                end.fmt(f)
+            }
+        }
+    }
+}
+
+/// Repeating interval, `Rn/interval` or `R/interval` when unbounded (4.5)
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct RecurringInterval {
+    /// Number of repetitions, or `None` if unbounded
+    pub repetitions: Option<u32>,
+    pub interval: Interval,
+}
+
+/// Alias of [`RecurringInterval`], after the "repeating interval" of the standard
+pub type RepeatingInterval = RecurringInterval;
+
+impl RecurringInterval {
+    /// Iterates over the start of each repetition.
+    ///
+    /// A `start/end` interval repeats every `end - start`, split into days,
+    /// hours, minutes and seconds. `duration/end` intervals are counted back
+    /// from the end, so their starts come in reverse chronological order.
+    /// Fails with [`Error::InvalidDate`](crate::Error::InvalidDate)
+    /// if the end of a `start/end` interval comes before its start.
+    pub fn occurrences(&self) -> Result<Occurrences, crate::Error> {
+        let (anchor, duration, backwards) = match self.interval {
+            Interval::StartEnd(start, end) => (start, between(&start, &end)?, false),
+            Interval::StartDuration(start, duration) => (start, duration, false),
+            Interval::DurationEnd(duration, end) => (end, duration, true),
+        };
+        Ok(Occurrences {
+            anchor,
+            duration,
+            backwards,
+            repetitions: self.repetitions,
+            index: 0,
+        })
+    }
+}
+
+/// Nanoseconds since the epoch, taking local times as UTC
+#[inline]
+fn nanos(datetime: &DateTime<Date, AnyTime>) -> i128 {
+    let (local, timezone) = match datetime.time {
+        AnyTime::Global(time) => (time.local, time.timezone),
+        AnyTime::Local(local) => (local, Timezone::Utc),
+    };
+    DateTime {
+        date: datetime.date,
+        time: GlobalTime { local, timezone },
+    }
+    .timestamp_nanos()
+}
+
+/// Exact duration from `start` to `end`, without years or months
+fn between(
+    start: &DateTime<Date, AnyTime>,
+    end: &DateTime<Date, AnyTime>,
+) -> Result<Duration, crate::Error> {
+    const NANOS_PER_MINUTE: i128 = 60_000_000_000;
+
+    let nanos = nanos(end) - nanos(start);
+    if nanos < 0 {
+        return Err(crate::Error::InvalidDate);
+    }
+    let minutes = nanos / NANOS_PER_MINUTE;
+    Ok(Duration {
+        days: (minutes / 1_440)
+            .try_into()
+            .or(Err(crate::Error::InvalidDate))?,
+        hours: (minutes / 60 % 24) as u32,
+        minutes: (minutes % 60) as u32,
+        seconds: (nanos % NANOS_PER_MINUTE / 1_000_000_000) as u32,
+        fraction: (nanos % 1_000_000_000) as u32,
+        ..Default::default()
+    })
+}
+
+/// Starts of the repetitions of a [`RecurringInterval`],
+/// ending early if a date overflows.
+#[derive(Clone, Debug)]
+pub struct Occurrences {
+    anchor: DateTime<Date, AnyTime>,
+    duration: Duration,
+    backwards: bool,
+    repetitions: Option<u32>,
+    index: u32,
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime<Date, AnyTime>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if matches!(self.repetitions, Some(repetitions) if self.index >= repetitions) {
+            return None;
+        }
+        // Scaling the duration rather than adding it repeatedly
+        // keeps the day of the month from drifting after clamping
+        let occurrence = if self.backwards {
+            let duration = self.duration.checked_mul(self.index.checked_add(1)?)?;
+            self.anchor.checked_sub(&duration)
+        } else {
+            let duration = self.duration.checked_mul(self.index)?;
+            self.anchor.checked_add(&duration)
+        };
+        let occurrence = occurrence.ok()?;
+        self.index = self.index.checked_add(1)?;
+        Some(occurrence)
+    }
+}
+
+/// Instants of an [`Interval`] at a regular step,
+/// ending early if a date overflows.
+#[derive(Clone, Debug)]
+pub struct Steps {
+    start: DateTime<Date, AnyTime>,
+    end: i128,
+    step: Duration,
+    inclusive: bool,
+    index: Option<u32>,
+}
+
+impl Iterator for Steps {
+    type Item = DateTime<Date, AnyTime>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index.take()?;
+        let instant = self
+            .start
+            .checked_add(&self.step.checked_mul(index)?)
+            .ok()?;
+        match nanos(&instant).cmp(&self.end) {
+            core::cmp::Ordering::Less => {}
+            core::cmp::Ordering::Equal if self.inclusive => {}
+            _ => return None,
+        }
+        self.index = index.checked_add(1);
+        Some(instant)
+    }
+}
+
+impl_fromstr_parse!(RecurringInterval, recurring_interval);
+
+impl Valid for RecurringInterval {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.interval.is_valid()
+    }
+}
+
+impl core::fmt::Display for RecurringInterval {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("R")?;
+        if let Some(repetitions) = self.repetitions {
+            write!(f, "{}", repetitions)?;
+        }
+        f.write_str("/")?;
+        self.interval.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_end() {
+        let interval = "2007-03-01T13:00:00Z/2008-05-11T15:30:00Z"
+            .parse::<Interval>()
+            .unwrap();
+        assert!(matches!(interval, Interval::StartEnd(..)));
+        assert_eq!(
+            interval.start().unwrap().to_string(),
+            "2007-03-01T13:00:00Z"
+        );
+        assert_eq!(interval.end().unwrap().to_string(), "2008-05-11T15:30:00Z");
+    }
+
+    #[test]
+    fn start_duration() {
+        let interval = "2007-03-01T13:00:00Z/P1Y2M10DT2H30M"
+            .parse::<Interval>()
+            .unwrap();
+        assert!(matches!(interval, Interval::StartDuration(..)));
+        assert_eq!(
+            interval.start().unwrap().to_string(),
+            "2007-03-01T13:00:00Z"
+        );
+        assert_eq!(interval.end().unwrap().to_string(), "2008-05-11T15:30:00Z");
+    }
+
+    #[test]
+    fn duration_end() {
+        let interval = "P1Y2M10DT2H30M/2008-05-11T15:30:00Z"
+            .parse::<Interval>()
+            .unwrap();
+        assert!(matches!(interval, Interval::DurationEnd(..)));
+        assert_eq!(
+            interval.start().unwrap().to_string(),
+            "2007-03-01T13:00:00Z"
+        );
+        assert_eq!(interval.end().unwrap().to_string(), "2008-05-11T15:30:00Z");
+    }
+
+    #[test]
+    fn abbreviated_end() {
+        let end = |s: &str| s.parse::<Interval>().unwrap().end().unwrap().to_string();
+
+        assert_eq!(end("2007-12-14T13:30/15:30"), "2007-12-14T15:30:00");
+        assert_eq!(end("2007-11-13T09:00/15T17:00"), "2007-11-15T17:00:00");
+        assert_eq!(end("2007-11-13T09:00/12-01T17:00"), "2007-12-01T17:00:00");
+        assert_eq!(
+            end("2007-12-14T13:30+01:00/15:30"),
+            "2007-12-14T15:30:00+01:00"
+        );
+        assert_eq!(end("2007-12-14T13:30Z/T15:30Z"), "2007-12-14T15:30:00Z");
+        for s in [
+            "2007-11-13T09:00/31T17:00",
+            "2021-400T13:30/15T17:00",
+            "2021-000T13:30/15T17:00",
+            "2021-W60-1T13:30/15T17:00",
+        ] {
+            assert!(
+                matches!(s.parse::<Interval>(), Err(crate::Error::InvalidDate)),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn date_endpoints() {
+        for (s, expected) in [
+            ("2020-01-01/P1M", "2020-01-01T00:00:00/P1M"),
+            (
+                "2020-01-01/2020-02-01",
+                "2020-01-01T00:00:00/2020-02-01T00:00:00",
+            ),
+            ("P1D/2020-01-01", "P1D/2020-01-01T00:00:00"),
+            (
+                "2020-W01-1/2020-01-10T12:00Z",
+                "2020-W01-1T00:00:00/2020-01-10T12:00:00Z",
+            ),
+        ] {
+            assert_eq!(s.parse::<Interval>().unwrap().to_string(), expected);
+        }
+        assert_eq!(
+            "2020-01-01/P1M"
+                .parse::<Interval>()
+                .unwrap()
+                .end()
+                .unwrap()
+                .to_string(),
+            "2020-02-01T00:00:00"
+        );
+        assert!("2020-01-01/2020-02-30".parse::<Interval>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        for s in [
+            "2007-03-01T13:00:00Z/2008-05-11T15:30:00Z",
+            "2007-03-01T13:00:00Z/P1Y2M10DT2H30M",
+            "P1Y2M10DT2H30M/2008-05-11T15:30:00Z",
+        ] {
+            assert_eq!(s.parse::<Interval>().unwrap().to_string(), s);
+        }
+        assert_eq!(
+            "2007-12-14T13:30/15:30"
+                .parse::<Interval>()
+                .unwrap()
+                .to_string(),
+            "2007-12-14T13:30:00/2007-12-14T15:30:00"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                "R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M"
+                    .parse::<RecurringInterval>()
+                    .unwrap()
+            ),
+            "R5/20080301T130000Z/P1Y2M10DT2H30M"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            "P1Y/P2M".parse::<Interval>(),
+            Err(crate::Error::InvalidFormat { offset: Some(4) })
+        ));
+        assert!(matches!(
+            "2007-03-01T13:00:00Z".parse::<Interval>(),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            "2007-03-01T13:00:00Z/".parse::<Interval>(),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn recurring_interval() {
+        let recurring = "R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M"
+            .parse::<RecurringInterval>()
+            .unwrap();
+        assert_eq!(recurring.repetitions, Some(5));
+        assert!(matches!(recurring.interval, Interval::StartDuration(..)));
+        assert_eq!(
+            recurring.to_string(),
+            "R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M"
+        );
+
+        let unbounded = "R/2008-03-01T13:00:00Z/P1D"
+            .parse::<RecurringInterval>()
+            .unwrap();
+        assert_eq!(unbounded.repetitions, None);
+        assert_eq!(unbounded.to_string(), "R/2008-03-01T13:00:00Z/P1D");
+        assert_eq!(
+            "R/2008-03-01T13:00:00Z/P1D"
+                .parse::<RepeatingInterval>()
+                .unwrap(),
+            unbounded
+        );
+
+        for s in [
+            "R5",
+            "R5/",
+            "Rx/2008-03-01T13:00:00Z/P1D",
+            "R5 /2008-03-01T13:00:00Z/P1D",
+            "R-1/2008-03-01T13:00:00Z/P1D",
+            "R99999999999/2008-03-01T13:00:00Z/P1D",
+            "2008-03-01T13:00:00Z/P1D",
+        ] {
+            assert!(
+                matches!(
+                    s.parse::<RecurringInterval>(),
+                    Err(crate::Error::InvalidFormat { .. })
+                ),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn iter_step() {
+        let steps = |interval: &str, step: &str, inclusive| -> Vec<String> {
+            interval
+                .parse::<Interval>()
+                .unwrap()
+                .iter_step(step.parse().unwrap(), inclusive)
+                .unwrap()
+                .map(|datetime| datetime.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            steps("2007-02-27T00:00:00Z/2007-03-02T00:00:00Z", "P1D", true),
+            [
+                "2007-02-27T00:00:00Z",
+                "2007-02-28T00:00:00Z",
+                "2007-03-01T00:00:00Z",
+                "2007-03-02T00:00:00Z",
+            ]
+        );
+        assert_eq!(
+            steps("2007-02-27T00:00:00Z/2007-03-02T00:00:00Z", "P1D", false),
+            [
+                "2007-02-27T00:00:00Z",
+                "2007-02-28T00:00:00Z",
+                "2007-03-01T00:00:00Z",
+            ]
+        );
+        assert_eq!(
+            steps("2008-01-31T13:00:00Z/P3M", "P1M", true),
+            [
+                "2008-01-31T13:00:00Z",
+                "2008-02-29T13:00:00Z",
+                "2008-03-31T13:00:00Z",
+                "2008-04-30T13:00:00Z",
+            ]
+        );
+        assert_eq!(
+            steps("PT2H30M/2007-03-01T13:00:00+01:00", "PT1H", false),
+            [
+                "2007-03-01T10:30:00+01:00",
+                "2007-03-01T11:30:00+01:00",
+                "2007-03-01T12:30:00+01:00",
+            ]
+        );
+        assert_eq!(
+            steps("2007-03-02T00:00:00Z/2007-03-01T00:00:00Z", "P1D", true),
+            [""; 0]
+        );
+
+        let interval: Interval = "2007-03-01T00:00:00Z/P1D".parse().unwrap();
+        assert!(matches!(
+            interval.iter_step("PT0S".parse().unwrap(), true),
+            Err(crate::Error::InvalidDate)
+        ));
+        assert!(matches!(
+            interval.iter_step(Duration::default(), false),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn occurrences() {
+        let occurrences = |s: &str| -> Vec<String> {
+            s.parse::<RecurringInterval>()
+                .unwrap()
+                .occurrences()
+                .unwrap()
+                .take(4)
+                .map(|datetime| datetime.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            occurrences("R3/2008-01-31T13:00:00Z/P1M"),
+            [
+                "2008-01-31T13:00:00Z",
+                "2008-02-29T13:00:00Z",
+                "2008-03-31T13:00:00Z",
+            ]
+        );
+        assert_eq!(
+            occurrences("R/2008-03-01T13:00:00/PT0.1S"),
+            [
+                "2008-03-01T13:00:00",
+                "2008-03-01T13:00:00.1",
+                "2008-03-01T13:00:00.2",
+                "2008-03-01T13:00:00.3",
+            ]
+        );
+        assert_eq!(
+            occurrences("R2/2008-03-01T13:00:00Z/2008-03-02T14:30:00+01:00"),
+            ["2008-03-01T13:00:00Z", "2008-03-02T13:30:00Z"]
+        );
+        assert_eq!(
+            occurrences("R3/2008-03-01T13:00:00Z/2008-03-01T13:00:01.123456789Z"),
+            [
+                "2008-03-01T13:00:00Z",
+                "2008-03-01T13:00:01.123456789Z",
+                "2008-03-01T13:00:02.246913578Z",
+            ]
+        );
+        assert_eq!(
+            occurrences("R2/P1D/2008-03-01T13:00:00Z"),
+            ["2008-02-29T13:00:00Z", "2008-02-28T13:00:00Z"]
+        );
+        assert_eq!(occurrences("R0/2008-03-01T13:00:00Z/P1D"), [""; 0]);
+        assert!(matches!(
+            "R/2008-03-02T13:00:00Z/2008-03-01T13:00:00Z"
+                .parse::<RecurringInterval>()
+                .unwrap()
+                .occurrences(),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+}
@@ -0,0 +1,312 @@
+#![cfg(feature = "jiff")]
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+    Timestamp, Zoned,
+};
+
+/// Fails for invalid dates and years out of the range supported by jiff.
+impl TryFrom<crate::YmdDate> for civil::Date {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(date: crate::YmdDate) -> Result<Self, Self::Error> {
+        let year = i16::try_from(date.year).or(Err(crate::Error::InvalidDate))?;
+        let month = i8::try_from(date.month).or(Err(crate::Error::InvalidDate))?;
+        let day = i8::try_from(date.day).or(Err(crate::Error::InvalidDate))?;
+        civil::Date::new(year, month, day).or(Err(crate::Error::InvalidDate))
+    }
+}
+
+/// Week and ordinal dates go through their calendar date.
+impl TryFrom<crate::Date> for civil::Date {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        crate::to_calendar_date(date)?.try_into()
+    }
+}
+
+/// Fails for leap seconds and hour 24, which jiff cannot represent.
+impl TryFrom<crate::LocalTime<crate::HmsTime>> for civil::Time {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(time: crate::LocalTime<crate::HmsTime>) -> Result<Self, Self::Error> {
+        let component = |value: u8| i8::try_from(value).or(Err(crate::Error::InvalidDate));
+        civil::Time::new(
+            component(time.naive.hour)?,
+            component(time.naive.minute)?,
+            component(time.naive.second)?,
+            time.nanosecond() as i32,
+        )
+        .or(Err(crate::Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for civil::DateTime {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        Ok(civil::DateTime::from_parts(
+            dt.date.try_into()?,
+            dt.time.try_into()?,
+        ))
+    }
+}
+
+/// Offset of a global time, and its local datetime
+#[inline]
+fn offset_datetime(
+    dt: crate::DateTime<crate::Date, crate::GlobalTime>,
+) -> Result<(Offset, civil::DateTime), crate::Error> {
+    let offset = Offset::from_seconds(dt.time.timezone.minutes() as i32 * 60)
+        .or(Err(crate::Error::InvalidDate))?;
+    let local = crate::DateTime {
+        date: dt.date,
+        time: dt.time.local,
+    };
+    Ok((offset, local.try_into()?))
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for Timestamp {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let (offset, local) = offset_datetime(dt)?;
+        offset
+            .to_timestamp(local)
+            .or(Err(crate::Error::InvalidDate))
+    }
+}
+
+/// The zone is the fixed offset of the time.
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for Zoned {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let (offset, local) = offset_datetime(dt)?;
+        let timestamp = offset
+            .to_timestamp(local)
+            .or(Err(crate::Error::InvalidDate))?;
+        Ok(timestamp.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+impl From<civil::Date> for crate::YmdDate {
+    #[inline]
+    fn from(date: civil::Date) -> Self {
+        Self {
+            year: date.year().into(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+impl From<civil::Date> for crate::Date {
+    #[inline]
+    fn from(date: civil::Date) -> Self {
+        crate::Date::YMD(date.into())
+    }
+}
+
+impl From<civil::Time> for crate::LocalTime<crate::HmsTime> {
+    #[inline]
+    fn from(time: civil::Time) -> Self {
+        Self {
+            naive: crate::HmsTime {
+                hour: time.hour() as u8,
+                minute: time.minute() as u8,
+                second: time.second() as u8,
+            },
+            fraction: time.subsec_nanosecond() as u32,
+            fraction_digits: 0,
+        }
+    }
+}
+
+impl From<civil::DateTime> for crate::DateTime<crate::Date, crate::LocalTime> {
+    #[inline]
+    fn from(dt: civil::DateTime) -> Self {
+        Self {
+            date: dt.date().into(),
+            time: dt.time().into(),
+        }
+    }
+}
+
+/// The timezone is written as `Z`.
+impl From<Timestamp> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    #[inline]
+    fn from(timestamp: Timestamp) -> Self {
+        let local = crate::DateTime::<crate::Date, crate::LocalTime>::from(
+            Offset::UTC.to_datetime(timestamp),
+        );
+        Self {
+            date: local.date,
+            time: crate::GlobalTime {
+                local: local.time,
+                timezone: crate::Timezone::Utc,
+            },
+        }
+    }
+}
+
+/// Keeps the offset of the zone at that instant, and fails
+/// for offsets that are not a whole number of minutes.
+impl TryFrom<&Zoned> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    type Error = crate::Error;
+
+    fn try_from(zoned: &Zoned) -> Result<Self, Self::Error> {
+        let offset = zoned.offset().seconds();
+        if offset % 60 != 0 {
+            return Err(crate::Error::InvalidDate);
+        }
+
+        let local = crate::DateTime::<crate::Date, crate::LocalTime>::from(zoned.datetime());
+        Ok(Self {
+            date: local.date,
+            time: crate::GlobalTime {
+                local: local.time,
+                timezone: crate::Timezone::Fixed((offset / 60) as i16),
+            },
+        })
+    }
+}
+
+impl TryFrom<Zoned> for crate::DateTime<crate::Date, crate::GlobalTime> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(zoned: Zoned) -> Result<Self, Self::Error> {
+        (&zoned).try_into()
+    }
+}
+
+impl From<crate::Weekday> for civil::Weekday {
+    #[inline]
+    fn from(weekday: crate::Weekday) -> Self {
+        match weekday {
+            crate::Weekday::Monday => civil::Weekday::Monday,
+            crate::Weekday::Tuesday => civil::Weekday::Tuesday,
+            crate::Weekday::Wednesday => civil::Weekday::Wednesday,
+            crate::Weekday::Thursday => civil::Weekday::Thursday,
+            crate::Weekday::Friday => civil::Weekday::Friday,
+            crate::Weekday::Saturday => civil::Weekday::Saturday,
+            crate::Weekday::Sunday => civil::Weekday::Sunday,
+        }
+    }
+}
+
+impl From<civil::Weekday> for crate::Weekday {
+    #[inline]
+    fn from(weekday: civil::Weekday) -> Self {
+        match weekday {
+            civil::Weekday::Monday => crate::Weekday::Monday,
+            civil::Weekday::Tuesday => crate::Weekday::Tuesday,
+            civil::Weekday::Wednesday => crate::Weekday::Wednesday,
+            civil::Weekday::Thursday => crate::Weekday::Thursday,
+            civil::Weekday::Friday => crate::Weekday::Friday,
+            civil::Weekday::Saturday => crate::Weekday::Saturday,
+            civil::Weekday::Sunday => crate::Weekday::Sunday,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_and_time() {
+        let expected = civil::date(2018, 1, 9);
+        for s in ["2018-01-09", "2018-W02-2", "2018-009"] {
+            let date: crate::Date = s.parse().unwrap();
+            assert_eq!(civil::Date::try_from(date).unwrap(), expected, "{}", s);
+        }
+        for date in [
+            crate::Date::O(crate::ODate {
+                year: 2018,
+                day: 366,
+            }),
+            crate::Date::YMD(crate::YmdDate {
+                year: 10_000,
+                month: 1,
+                day: 1,
+            }),
+        ] {
+            assert!(civil::Date::try_from(date).is_err());
+        }
+        assert_eq!(crate::Date::from(expected).to_string(), "2018-01-09");
+        assert_eq!(
+            crate::Weekday::from(expected.weekday()),
+            crate::Weekday::Tuesday
+        );
+        assert_eq!(
+            civil::Weekday::from(crate::Weekday::Tuesday),
+            expected.weekday()
+        );
+
+        let time: crate::LocalTime = "13:42:05.25".parse().unwrap();
+        let converted = civil::Time::try_from(time).unwrap();
+        assert_eq!(converted, civil::time(13, 42, 5, 250_000_000));
+        assert_eq!(crate::LocalTime::from(converted), time);
+        for s in ["23:59:60", "24:00:00"] {
+            assert!(civil::Time::try_from(s.parse::<crate::LocalTime>().unwrap()).is_err());
+        }
+
+        let datetime: crate::DateTime<crate::Date, crate::LocalTime> =
+            "2018-W02-2T13:42:05.25".parse().unwrap();
+        let civil = civil::DateTime::try_from(datetime).unwrap();
+        assert_eq!(civil, expected.to_datetime(converted));
+        assert_eq!(
+            crate::DateTime::from(civil).to_string(),
+            "2018-01-09T13:42:05.25"
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        let global: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2018-08-02T13:42:00.5+02:00".parse().unwrap();
+        let timestamp = Timestamp::try_from(global).unwrap();
+        assert_eq!(timestamp, "2018-08-02T11:42:00.5Z".parse().unwrap());
+        assert_eq!(
+            crate::DateTime::from(timestamp).to_string(),
+            "2018-08-02T11:42:00.5Z"
+        );
+
+        let leap_second: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2016-12-31T23:59:60Z".parse().unwrap();
+        assert!(Timestamp::try_from(leap_second).is_err());
+    }
+
+    #[test]
+    fn zoned() {
+        let global: crate::DateTime<crate::Date, crate::GlobalTime> =
+            "2018-08-02T13:42:00.5-05:30".parse().unwrap();
+        let zoned = Zoned::try_from(global).unwrap();
+        assert_eq!(zoned.offset(), Offset::from_seconds(-330 * 60).unwrap());
+        assert_eq!(
+            zoned.datetime(),
+            civil::datetime(2018, 8, 2, 13, 42, 0, 500_000_000)
+        );
+        assert_eq!(
+            crate::DateTime::try_from(&zoned).unwrap().to_string(),
+            "2018-08-02T13:42:00.5-05:30"
+        );
+
+        let seconds = zoned
+            .timestamp()
+            .to_zoned(TimeZone::fixed(Offset::from_seconds(30).unwrap()));
+        assert!(matches!(
+            crate::DateTime::try_from(seconds),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+}
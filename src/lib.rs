@@ -1,3 +1,5 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 // https://github.com/rust-lang/cargo/issues/383#issuecomment-720873790
 #[cfg(doctest)]
 mod test_readme {
@@ -15,25 +17,42 @@ extern crate nom;
 
 #[derive(Debug, Copy, Clone)]
 pub enum Error {
-    InvalidFormat,
+    /// The input is not well formed; `offset` is the byte offset
+    /// where parsing failed, if the error comes from a parser
+    InvalidFormat {
+        offset: Option<usize>,
+    },
     InvalidDate,
+    /// The decimal fraction is not below one
+    InvalidFraction,
+}
+
+impl Default for Error {
+    fn default() -> Self {
+        Error::InvalidFormat { offset: None }
+    }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         use Error::*;
         match self {
-            InvalidFormat => write!(f, "Invalid ISO-8601 format"),
+            InvalidFormat { offset: None } => write!(f, "Invalid ISO-8601 format"),
+            InvalidFormat {
+                offset: Some(offset),
+            } => write!(f, "Invalid ISO-8601 format at byte {}", offset),
             InvalidDate => write!(f, "Invalid date or time"),
+            InvalidFraction => write!(f, "Invalid decimal fraction"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 macro_rules! impl_fromstr_parse {
     ($ty:ty, $func:ident) => {
-        impl std::str::FromStr for $ty {
+        impl core::str::FromStr for $ty {
             type Err = crate::Error;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -41,22 +60,470 @@ macro_rules! impl_fromstr_parse {
 
                 let res = crate::parse::$func(s.as_bytes())
                     .map(|x| x.1)
-                    .or(Err(Self::Err::InvalidFormat))?;
+                    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))?;
 
                 res.is_valid().then(|| res).ok_or(Self::Err::InvalidDate)
             }
         }
+
+        impl TryFrom<&str> for $ty {
+            type Error = crate::Error;
+
+            #[inline]
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl crate::ParsePrefix for $ty {
+            fn parse_prefix(s: &str) -> Result<(Self, &str), crate::Error> {
+                use crate::Valid;
+
+                let (rest, res) = crate::parse::$func(s.as_bytes())
+                    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))?;
+                let offset = s.len() - rest.len();
+                let rest = s.get(offset..).ok_or(crate::Error::InvalidFormat {
+                    offset: Some(offset),
+                })?;
+
+                res.is_valid()
+                    .then_some((res, rest))
+                    .ok_or(crate::Error::InvalidDate)
+            }
+        }
+
+        impl crate::ParseLenient for $ty {
+            fn parse_lenient(s: &str) -> Result<Self, crate::Error> {
+                crate::parse::$func(s.as_bytes())
+                    .map(|x| x.1)
+                    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))
+            }
+        }
+    };
+}
+
+macro_rules! impl_parse_strict {
+    ($ty:ty, $func:ident) => {
+        impl crate::ParseStrict for $ty {
+            fn parse_strict(s: &str) -> Result<Self, crate::Error> {
+                use crate::Valid;
+
+                let res = crate::parse::$func(s.as_bytes())
+                    .map(|x| x.1)
+                    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))?;
+
+                res.is_valid().then(|| res).ok_or(crate::Error::InvalidDate)
+            }
+        }
+    };
+}
+
+macro_rules! impl_parse_expanded {
+    ($ty:ty, $func:ident) => {
+        impl crate::ParseExpanded for $ty {
+            fn parse_expanded(s: &str, extra_digits: usize) -> Result<Self, crate::Error> {
+                use crate::Valid;
+
+                let res = crate::parse::$func(extra_digits)(s.as_bytes())
+                    .map(|x| x.1)
+                    .map_err(|err| crate::parse::invalid_format(s.as_bytes(), err))?;
+
+                res.is_valid().then(|| res).ok_or(crate::Error::InvalidDate)
+            }
+        }
+    };
+}
+
+/// Implements `Display` with [`WriteIso8601`], in the extended format
+/// or in the basic format with the alternate flag `{:#}`
+macro_rules! impl_display_iso8601 {
+    ($ty:ty) => {
+        impl core::fmt::Display for $ty {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                crate::WriteIso8601::write_iso8601(self, f, !f.alternate())
+            }
+        }
     };
 }
 
 pub mod chrono;
+mod clock;
 mod date;
 mod datetime;
+mod duration;
+mod hifitime;
+mod interval;
+mod jiff;
 mod parse;
+mod serde;
 mod time;
+pub mod time_v03;
 
-pub use {date::*, datetime::*, time::*};
+#[cfg(feature = "clock")]
+pub use clock::*;
+pub use {date::*, datetime::*, duration::*, interval::*, time::*};
 
 pub trait Valid {
     fn is_valid(&self) -> bool;
 }
+
+/// Parsing that skips the [`Valid`] check.
+///
+/// This is a data repair escape hatch for legacy systems that emit
+/// placeholders such as `2024-00-00`: the input must still be well formed,
+/// but out of range components are kept as is and must be handled by the caller.
+pub trait ParseLenient: Sized {
+    fn parse_lenient(s: &str) -> Result<Self, Error>;
+}
+
+/// Parsing of a value at the start of a string,
+/// returning it with the input that follows it.
+///
+/// ```
+/// use iso_8601::*;
+///
+/// let (date, rest) = Date::parse_prefix("2018-08-02 – café").unwrap();
+/// assert_eq!(date.to_string(), "2018-08-02");
+/// assert_eq!(rest, " – café");
+/// ```
+pub trait ParsePrefix: Sized {
+    fn parse_prefix(s: &str) -> Result<(Self, &str), Error>;
+}
+
+/// Parsing that commits to a single format.
+///
+/// The whole input must be written either in the extended format or in
+/// the basic format: mixed representations such as `2018-0812` or
+/// `16:43:52+0200` are rejected, as is any trailing input.
+pub trait ParseStrict: Sized {
+    fn parse_strict(s: &str) -> Result<Self, Error>;
+}
+
+/// Parsing of dates with an expanded year of exactly `4 + extra_digits`
+/// digits, as agreed upon by the communicating parties (4.1.2.4).
+///
+/// `FromStr` recognizes expanded years of any length in the extended
+/// format only, where a separator ends the year. Fixing the number
+/// of digits also allows the basic format:
+///
+/// ```
+/// use iso_8601::*;
+///
+/// let date = Date::parse_expanded("+0120240704", 2).unwrap();
+/// assert_eq!(date.to_string(), "+12024-07-04");
+/// assert!(Date::parse_expanded("2024-07-04", 2).is_err());
+/// ```
+///
+/// Years are stored as an `i32`, which fits at most six extra digits.
+pub trait ParseExpanded: Sized {
+    fn parse_expanded(s: &str, extra_digits: usize) -> Result<Self, Error>;
+}
+
+/// Formatting into any [`core::fmt::Write`], without allocating.
+///
+/// `Display` writes the extended format, or the basic format, which leaves
+/// out the `-` and `:` separators, with the alternate flag:
+///
+/// ```
+/// use iso_8601::*;
+///
+/// let datetime: DateTime<Date, GlobalTime> = "2024-07-04T13:42:05+02:00".parse().unwrap();
+/// assert_eq!(format!("{}", datetime), "2024-07-04T13:42:05+02:00");
+/// assert_eq!(format!("{:#}", datetime), "20240704T134205+0200");
+/// ```
+pub trait WriteIso8601 {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result;
+}
+
+/// Supported notations, as `(name, example)` pairs
+pub const FORMATS: &[(&str, &str)] = &[
+    ("extended calendar date", "2024-07-04"),
+    ("basic calendar date", "20240704"),
+    ("extended week date", "2024-W27-4"),
+    ("basic week date", "2024W274"),
+    ("extended ordinal date", "2024-186"),
+    ("basic ordinal date", "2024186"),
+    ("extended year and month", "2024-07"),
+    ("extended week", "2024-W27"),
+    ("basic week", "2024W27"),
+    ("year", "2024"),
+    ("expanded year", "+0100000000"),
+    ("expanded calendar date", "+012024-07-04"),
+    ("century", "20"),
+    ("extended local time", "13:42:05"),
+    ("basic local time", "T134205"),
+    ("extended hour and minute", "13:42"),
+    ("basic hour and minute", "T1342"),
+    ("hour", "T13"),
+    ("local time with decimal fraction", "13:42:05.25"),
+    ("local time with decimal comma", "13:42:05,25"),
+    ("UTC time", "13:42:05Z"),
+    ("extended time with offset", "13:42:05+02:00"),
+    ("basic time with offset", "T134205+0200"),
+    ("time with hour offset", "13:42:05-05"),
+    ("extended datetime", "2024-07-04T13:42:05+02:00"),
+    ("basic datetime", "20240704T134205Z"),
+    ("week datetime", "2024-W27-4T13:42:05Z"),
+    ("ordinal datetime", "2024-186T13:42:05Z"),
+];
+
+/// Rounds half away from zero like `f64::round`, which needs `std`
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    // past 2^52, or for NaN and infinities, there is no fractional part
+    if !x.is_finite() || x.abs() >= 4_503_599_627_370_496. {
+        return x;
+    }
+    let trunc = x as i64 as f64;
+    match x - trunc {
+        diff if diff >= 0.5 => trunc + 1.,
+        diff if diff <= -0.5 => trunc - 1.,
+        _ => trunc,
+    }
+}
+
+/// Parses every input, silently skipping the ones that fail.
+pub fn parse_many_ok<'a, T>(
+    inputs: impl Iterator<Item = &'a str> + 'a,
+) -> impl Iterator<Item = T> + 'a
+where
+    T: core::str::FromStr<Err = Error> + 'a,
+{
+    inputs.filter_map(|s| s.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_default() {
+        assert!(matches!(
+            Error::default(),
+            Error::InvalidFormat { offset: None }
+        ));
+    }
+
+    #[test]
+    fn error_offset() {
+        let err = "2018-08-02T25:xx".parse::<DateTime<Date, GlobalTime>>();
+        assert!(matches!(
+            err,
+            Err(Error::InvalidFormat { offset: Some(13) })
+        ));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid ISO-8601 format at byte 13"
+        );
+        assert!(matches!(
+            "2018-08-02T".parse::<DateTime<Date, GlobalTime>>(),
+            Err(Error::InvalidFormat { offset: Some(11) })
+        ));
+        assert!(matches!(
+            Date::parse_strict("2018-08-02 "),
+            Err(Error::InvalidFormat { offset: Some(10) })
+        ));
+        assert_eq!(Error::default().to_string(), "Invalid ISO-8601 format");
+    }
+
+    #[test]
+    fn formats() {
+        for (name, example) in FORMATS {
+            assert!(
+                example.parse::<PartialDateTime>().is_ok(),
+                "{} ({}) does not parse",
+                name,
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn parse_lenient() {
+        assert!(matches!(
+            "2024-00-00".parse::<YmdDate>(),
+            Err(Error::InvalidDate)
+        ));
+        assert_eq!(
+            YmdDate::parse_lenient("2024-00-00").unwrap(),
+            YmdDate {
+                year: 2024,
+                month: 0,
+                day: 0,
+            }
+        );
+        assert!(matches!(
+            YmdDate::parse_lenient("2024-0-0"),
+            Err(Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let (datetime, rest) =
+            DateTime::<Date, GlobalTime>::parse_prefix("2018-08-02T16:43:52Z → café").unwrap();
+        assert_eq!(datetime.to_string(), "2018-08-02T16:43:52Z");
+        assert_eq!(rest, " → café");
+
+        let (time, rest) = LocalTime::<HmsTime>::parse_prefix("16:43:52é").unwrap();
+        assert_eq!(time.to_string(), "16:43:52");
+        assert_eq!(rest, "é");
+
+        let (time, rest) = AnyTime::<HmsTime>::parse_prefix("16:43:52\u{2212}").unwrap();
+        assert_eq!(time.to_string(), "16:43:52");
+        assert_eq!(rest, "\u{2212}");
+
+        let (date, rest) = Date::parse_prefix("2018-08-02").unwrap();
+        assert_eq!(date.to_string(), "2018-08-02");
+        assert_eq!(rest, "");
+
+        assert!(matches!(
+            Date::parse_prefix("2018-02-30T"),
+            Err(Error::InvalidDate)
+        ));
+        assert!(matches!(
+            Date::parse_prefix("é2018-08-02"),
+            Err(Error::InvalidFormat { offset: Some(0) })
+        ));
+    }
+
+    #[test]
+    fn parse_strict() {
+        assert!("2018-0812".parse::<Date>().is_ok());
+        assert!(matches!(
+            Date::parse_strict("2018-0812"),
+            Err(Error::InvalidFormat { .. })
+        ));
+        assert!(Date::parse_strict("2018-08-12").is_ok());
+        assert!(Date::parse_strict("20180812").is_ok());
+        assert!(matches!(
+            Date::parse_strict("2018-02-30"),
+            Err(Error::InvalidDate)
+        ));
+
+        assert!("16:4352".parse::<LocalTime<HmTime>>().is_ok());
+        assert!(AnyTime::<HmsTime>::parse_strict("16:4352").is_err());
+        assert!("16:43:52+0200".parse::<GlobalTime>().is_ok());
+        assert!(AnyTime::<HmsTime>::parse_strict("16:43:52+0200").is_err());
+        assert!(AnyTime::<HmsTime>::parse_strict("16:43:52+02:00").is_ok());
+        assert!(AnyTime::<HmsTime>::parse_strict("T164352,5+0200").is_ok());
+        assert!(AnyTime::<HmsTime>::parse_strict("16:43:52-05").is_ok());
+
+        assert!("2018-08-12T164352Z"
+            .parse::<DateTime<Date, AnyTime>>()
+            .is_ok());
+        assert!(DateTime::<Date, AnyTime>::parse_strict("2018-08-12T164352Z").is_err());
+        assert!(DateTime::<Date, AnyTime>::parse_strict("20180812T16:43:52Z").is_err());
+        assert!(DateTime::<Date, AnyTime>::parse_strict("2018-08-12T16:43:52Z").is_ok());
+        assert!(DateTime::<Date, AnyTime>::parse_strict("2018W327T164352").is_ok());
+    }
+
+    #[test]
+    fn write_iso8601() {
+        struct StackBuf {
+            buf: [u8; 32],
+            len: usize,
+        }
+
+        impl core::fmt::Write for StackBuf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let write = |s: &str, extended| {
+            let datetime: PartialDateTime = s.parse().unwrap();
+            let mut buf = StackBuf {
+                buf: [0; 32],
+                len: 0,
+            };
+            datetime.write_iso8601(&mut buf, extended).unwrap();
+            core::str::from_utf8(&buf.buf[..buf.len])
+                .unwrap()
+                .to_owned()
+        };
+        for (extended, basic) in [
+            ("2024-07-04T13:42:05.25+02:00", "20240704T134205.25+0200"),
+            ("2024-W27-4T13:42Z", "2024W274T1342Z"),
+            ("2024-186T13-05:30", "2024186T13-0530"),
+            ("-0333-06-11", "-03330611"),
+            ("2024-07", "2024-07"),
+            ("T13:42:05", "T134205"),
+        ] {
+            assert_eq!(write(extended, true), extended);
+            assert_eq!(write(extended, false), basic);
+            assert_eq!(write(basic, false), basic);
+            assert_eq!(
+                extended.parse::<PartialDateTime>().unwrap().to_string(),
+                extended
+            );
+            assert_eq!(
+                format!("{:#}", extended.parse::<PartialDateTime>().unwrap()),
+                basic
+            );
+        }
+
+        let mut buf = StackBuf {
+            buf: [0; 32],
+            len: 0,
+        };
+        let datetime: DateTime<Date, GlobalTime> = "2024-07-04T13:42:05.25+02:00".parse().unwrap();
+        assert!(datetime.write_iso8601(&mut buf, true).is_ok());
+        assert!(datetime.write_iso8601(&mut buf, true).is_err());
+    }
+
+    #[test]
+    fn round() {
+        for x in [
+            0.,
+            0.4999999999999999,
+            0.5,
+            1.5,
+            2.5,
+            -0.5,
+            -2.4,
+            -2.5,
+            123456.789,
+            4_503_599_627_370_495.5,
+            1e300,
+            f64::INFINITY,
+        ] {
+            assert_eq!(super::round(x), x.round(), "{}", x);
+        }
+        assert!(super::round(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn parse_many_ok() {
+        let inputs = [
+            "2018-08-02",
+            "2018-W02-2",
+            "2018-102",
+            "2018-02-30",
+            "20180802",
+            "not a date",
+            "2020-366",
+            "2018-13-01",
+            "-0333-06-11",
+            "2016-02-29",
+        ];
+        let dates: Vec<Date> = super::parse_many_ok(inputs.iter().copied()).collect();
+
+        assert_eq!(dates.len(), 7);
+        assert_eq!(
+            dates[0],
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            })
+        );
+    }
+}
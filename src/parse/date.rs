@@ -6,62 +6,129 @@ use nom::{
     branch::alt,
     bytes::complete::take_while_m_n,
     character::complete::char,
-    combinator::{complete, cond, map, opt},
-    sequence::{pair, separated_pair, tuple},
+    combinator::{complete, cond, map, map_opt, not, opt},
+    sequence::{pair, separated_pair, terminated, tuple},
 };
 
+/// Maximum number of digits of an expanded year, enough for any `i32`
+const EXPANDED_YEAR_DIGITS: usize = 10;
+
 #[inline]
-fn positive_century(i: &[u8]) -> ParseResult<u8> {
+fn positive_century(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn century(i: &[u8]) -> ParseResult<i8> {
+fn century(i: &[u8]) -> ParseResult<'_, i8> {
     map(pair(opt(sign), positive_century), |(sign, century)| {
         sign.unwrap_or(1) * century as i8
     })(i)
 }
 
 #[inline]
-// TODO support expanded year
-fn positive_year(i: &[u8]) -> ParseResult<u16> {
+fn positive_year(i: &[u8]) -> ParseResult<'_, u16> {
     map(take_while_m_n(4, 4, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn year(i: &[u8]) -> ParseResult<i16> {
+fn four_digit_year(i: &[u8]) -> ParseResult<'_, i32> {
     map(pair(opt(sign), positive_year), |(sign, year)| {
-        sign.unwrap_or(1) as i16 * year as i16
+        sign.unwrap_or(1) as i32 * year as i32
     })(i)
 }
 
+/// Expanded year (4.1.2.4): a mandatory sign
+/// followed by more than four and at most `digits` digits.
+#[inline]
+fn expanded_year<'a>(digits: usize) -> impl FnMut(&'a [u8]) -> ParseResult<'a, i32> {
+    map_opt(
+        terminated(
+            pair(sign, take_while_m_n(5, digits, is_digit)),
+            not(take_while_m_n(1, 1, is_digit)),
+        ),
+        |(sign, year)| (sign as i64 * buf_to_int::<i64>(year)).try_into().ok(),
+    )
+}
+
+/// Expanded year with exactly `4 + extra_digits` digits, a number agreed
+/// upon beforehand so that the year is also delimited in the basic format.
 #[inline]
-fn month(i: &[u8]) -> ParseResult<u8> {
+fn agreed_year(i: &[u8], extra_digits: usize) -> ParseResult<'_, i32> {
+    let digits = extra_digits.saturating_add(4);
+    if digits > EXPANDED_YEAR_DIGITS {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            i,
+            nom::error::ErrorKind::TooLarge,
+        )));
+    }
+    map_opt(
+        pair(sign, take_while_m_n(digits, digits, is_digit)),
+        |(sign, year)| (sign as i64 * buf_to_int::<i64>(year)).try_into().ok(),
+    )(i)
+}
+
+#[inline]
+fn year(i: &[u8]) -> ParseResult<'_, i32> {
+    alt((expanded_year(EXPANDED_YEAR_DIGITS), four_digit_year))(i)
+}
+
+/// The number of digits of an expanded year cannot be told apart
+/// from the following components in the basic format,
+/// so it is only recognized in the extended format.
+#[inline]
+fn year_format(i: &[u8], extended: bool) -> ParseResult<'_, i32> {
+    if extended {
+        year(i)
+    } else {
+        four_digit_year(i)
+    }
+}
+
+/// A date in the basic format is not followed by more digits,
+/// which leaves them to a lone expanded year.
+#[inline]
+fn basic<'a, O>(
+    parser: impl FnMut(&'a [u8]) -> ParseResult<'a, O>,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, O> {
+    terminated(parser, not(take_while_m_n(1, 1, is_digit)))
+}
+
+#[inline]
+pub(super) fn month(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn day(i: &[u8]) -> ParseResult<u8> {
+pub(super) fn day(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn year_week(i: &[u8]) -> ParseResult<u8> {
+fn year_week(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn year_day(i: &[u8]) -> ParseResult<u16> {
+fn year_day(i: &[u8]) -> ParseResult<'_, u16> {
     map(take_while_m_n(3, 3, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn week_day(i: &[u8]) -> ParseResult<u8> {
+fn week_day(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(1, 1, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn date_ymd_format(i: &[u8], extended: bool) -> ParseResult<YmdDate> {
+fn date_ymd_format(i: &[u8], extended: bool) -> ParseResult<'_, YmdDate> {
+    date_ymd_with(i, |i| year_format(i, extended), extended)
+}
+
+#[inline]
+fn date_ymd_with<'a>(
+    i: &'a [u8],
+    year: impl FnMut(&'a [u8]) -> ParseResult<'a, i32>,
+    extended: bool,
+) -> ParseResult<'a, YmdDate> {
     map(
         tuple((
             year,
@@ -75,22 +142,31 @@ fn date_ymd_format(i: &[u8], extended: bool) -> ParseResult<YmdDate> {
 }
 
 #[inline]
-fn date_ymd_basic(i: &[u8]) -> ParseResult<YmdDate> {
-    date_ymd_format(i, false)
+fn date_ymd_basic(i: &[u8]) -> ParseResult<'_, YmdDate> {
+    basic(|i| date_ymd_format(i, false))(i)
 }
 
 #[inline]
-fn date_ymd_extended(i: &[u8]) -> ParseResult<YmdDate> {
+fn date_ymd_extended(i: &[u8]) -> ParseResult<'_, YmdDate> {
     date_ymd_format(i, true)
 }
 
 #[inline]
-pub fn date_ymd(i: &[u8]) -> ParseResult<YmdDate> {
+pub fn date_ymd(i: &[u8]) -> ParseResult<'_, YmdDate> {
     alt((date_ymd_extended, date_ymd_basic))(i)
 }
 
 #[inline]
-fn date_wd_format(i: &[u8], extended: bool) -> ParseResult<WdDate> {
+fn date_wd_format(i: &[u8], extended: bool) -> ParseResult<'_, WdDate> {
+    date_wd_with(i, |i| year_format(i, extended), extended)
+}
+
+#[inline]
+fn date_wd_with<'a>(
+    i: &'a [u8],
+    year: impl FnMut(&'a [u8]) -> ParseResult<'a, i32>,
+    extended: bool,
+) -> ParseResult<'a, WdDate> {
     map(
         tuple((
             year,
@@ -105,22 +181,31 @@ fn date_wd_format(i: &[u8], extended: bool) -> ParseResult<WdDate> {
 }
 
 #[inline]
-fn date_wd_basic(i: &[u8]) -> ParseResult<WdDate> {
-    date_wd_format(i, false)
+fn date_wd_basic(i: &[u8]) -> ParseResult<'_, WdDate> {
+    basic(|i| date_wd_format(i, false))(i)
 }
 
 #[inline]
-fn date_wd_extended(i: &[u8]) -> ParseResult<WdDate> {
+fn date_wd_extended(i: &[u8]) -> ParseResult<'_, WdDate> {
     date_wd_format(i, true)
 }
 
 #[inline]
-pub fn date_wd(i: &[u8]) -> ParseResult<WdDate> {
+pub fn date_wd(i: &[u8]) -> ParseResult<'_, WdDate> {
     alt((date_wd_extended, date_wd_basic))(i)
 }
 
 #[inline]
-fn date_o_format(i: &[u8], extended: bool) -> ParseResult<ODate> {
+fn date_o_format(i: &[u8], extended: bool) -> ParseResult<'_, ODate> {
+    date_o_with(i, |i| year_format(i, extended), extended)
+}
+
+#[inline]
+fn date_o_with<'a>(
+    i: &'a [u8],
+    year: impl FnMut(&'a [u8]) -> ParseResult<'a, i32>,
+    extended: bool,
+) -> ParseResult<'a, ODate> {
     map(
         separated_pair(year, cond(extended, char('-')), year_day),
         |(year, day)| ODate { year, day },
@@ -128,22 +213,22 @@ fn date_o_format(i: &[u8], extended: bool) -> ParseResult<ODate> {
 }
 
 #[inline]
-fn date_o_basic(i: &[u8]) -> ParseResult<ODate> {
-    date_o_format(i, false)
+fn date_o_basic(i: &[u8]) -> ParseResult<'_, ODate> {
+    basic(|i| date_o_format(i, false))(i)
 }
 
 #[inline]
-fn date_o_extended(i: &[u8]) -> ParseResult<ODate> {
+fn date_o_extended(i: &[u8]) -> ParseResult<'_, ODate> {
     date_o_format(i, true)
 }
 
 #[inline]
-pub fn date_o(i: &[u8]) -> ParseResult<ODate> {
+pub fn date_o(i: &[u8]) -> ParseResult<'_, ODate> {
     alt((date_o_extended, date_o_basic))(i)
 }
 
 #[inline]
-pub fn date(i: &[u8]) -> ParseResult<Date> {
+pub fn date(i: &[u8]) -> ParseResult<'_, Date> {
     alt((
         complete(map(date_wd, Date::WD)),
         complete(map(date_ymd, Date::YMD)),
@@ -152,63 +237,128 @@ pub fn date(i: &[u8]) -> ParseResult<Date> {
 }
 
 #[inline]
-fn date_w_format(i: &[u8], extended: bool) -> ParseResult<WDate> {
+pub(super) fn date_format(i: &[u8], extended: bool) -> ParseResult<'_, Date> {
+    alt((
+        complete(map(|i| date_wd_format(i, extended), Date::WD)),
+        complete(map(|i| date_ymd_format(i, extended), Date::YMD)),
+        complete(map(|i| date_o_format(i, extended), Date::O)),
+    ))(i)
+}
+
+#[inline]
+pub fn date_strict(i: &[u8]) -> ParseResult<'_, Date> {
+    strict(date_format)(i)
+}
+
+#[inline]
+pub fn date_ymd_expanded<'a>(
+    extra_digits: usize,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, YmdDate> {
+    let year = move |i: &'a [u8]| agreed_year(i, extra_digits);
+    alt((
+        move |i| date_ymd_with(i, year, true),
+        basic(move |i| date_ymd_with(i, year, false)),
+    ))
+}
+
+#[inline]
+pub fn date_wd_expanded<'a>(
+    extra_digits: usize,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, WdDate> {
+    let year = move |i: &'a [u8]| agreed_year(i, extra_digits);
+    alt((
+        move |i| date_wd_with(i, year, true),
+        basic(move |i| date_wd_with(i, year, false)),
+    ))
+}
+
+#[inline]
+pub fn date_o_expanded<'a>(extra_digits: usize) -> impl FnMut(&'a [u8]) -> ParseResult<'a, ODate> {
+    let year = move |i: &'a [u8]| agreed_year(i, extra_digits);
+    alt((
+        move |i| date_o_with(i, year, true),
+        basic(move |i| date_o_with(i, year, false)),
+    ))
+}
+
+/// Dates whose year has a sign and exactly `4 + extra_digits` digits,
+/// in either format (4.1.2.4)
+#[inline]
+pub fn date_expanded<'a>(extra_digits: usize) -> impl FnMut(&'a [u8]) -> ParseResult<'a, Date> {
+    alt((
+        complete(map(date_wd_expanded(extra_digits), Date::WD)),
+        complete(map(date_ymd_expanded(extra_digits), Date::YMD)),
+        complete(map(date_o_expanded(extra_digits), Date::O)),
+    ))
+}
+
+#[inline]
+fn date_w_format(i: &[u8], extended: bool) -> ParseResult<'_, WDate> {
     map(
-        tuple((year, cond(extended, char('-')), char('W'), year_week)),
+        tuple((
+            |i| year_format(i, extended),
+            cond(extended, char('-')),
+            char('W'),
+            year_week,
+        )),
         |(year, _, _, week)| WDate { year, week },
     )(i)
 }
 
 #[inline]
-fn date_w_basic(i: &[u8]) -> ParseResult<WDate> {
-    date_w_format(i, false)
+fn date_w_basic(i: &[u8]) -> ParseResult<'_, WDate> {
+    basic(|i| date_w_format(i, false))(i)
 }
 
 #[inline]
-fn date_w_extended(i: &[u8]) -> ParseResult<WDate> {
+fn date_w_extended(i: &[u8]) -> ParseResult<'_, WDate> {
     date_w_format(i, true)
 }
 
 #[inline]
-pub fn date_w(i: &[u8]) -> ParseResult<WDate> {
+pub fn date_w(i: &[u8]) -> ParseResult<'_, WDate> {
     alt((date_w_extended, date_w_basic))(i)
 }
 
 #[inline]
-fn date_ym_format(i: &[u8], extended: bool) -> ParseResult<YmDate> {
+fn date_ym_format(i: &[u8], extended: bool) -> ParseResult<'_, YmDate> {
     map(
-        separated_pair(year, cond(extended, char('-')), month),
+        separated_pair(
+            |i| year_format(i, extended),
+            cond(extended, char('-')),
+            month,
+        ),
         |(year, month)| YmDate { year, month },
     )(i)
 }
 
 #[inline]
-fn date_ym_basic(i: &[u8]) -> ParseResult<YmDate> {
-    date_ym_format(i, false)
+fn date_ym_basic(i: &[u8]) -> ParseResult<'_, YmDate> {
+    basic(|i| date_ym_format(i, false))(i)
 }
 
 #[inline]
-fn date_ym_extended(i: &[u8]) -> ParseResult<YmDate> {
+fn date_ym_extended(i: &[u8]) -> ParseResult<'_, YmDate> {
     date_ym_format(i, true)
 }
 
 #[inline]
-pub fn date_ym(i: &[u8]) -> ParseResult<YmDate> {
+pub fn date_ym(i: &[u8]) -> ParseResult<'_, YmDate> {
     alt((date_ym_extended, date_ym_basic))(i)
 }
 
 #[inline]
-pub fn date_y(i: &[u8]) -> ParseResult<YDate> {
+pub fn date_y(i: &[u8]) -> ParseResult<'_, YDate> {
     map(year, |year| YDate { year })(i)
 }
 
 #[inline]
-pub fn date_c(i: &[u8]) -> ParseResult<CDate> {
+pub fn date_c(i: &[u8]) -> ParseResult<'_, CDate> {
     map(century, |century| CDate { century })(i)
 }
 
 #[inline]
-pub fn date_approx(i: &[u8]) -> ParseResult<ApproxDate> {
+pub fn date_approx(i: &[u8]) -> ParseResult<'_, ApproxDate> {
     alt((
         map(date, Into::into),
         map(date_w, ApproxDate::W),
@@ -219,6 +369,7 @@ pub fn date_approx(i: &[u8]) -> ParseResult<ApproxDate> {
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
 
@@ -232,6 +383,61 @@ mod tests {
         assert_eq!(super::year(b"2018"), Ok((&[][..], 2018)));
         assert_eq!(super::year(b"+2018"), Ok((&[][..], 2018)));
         assert_eq!(super::year(b"-2018"), Ok((&[][..], -2018)));
+        assert_eq!(super::year(b"+0100000000"), Ok((&[][..], 100_000_000)));
+        assert_eq!(super::year(b"-0000100000-"), Ok((&b"-"[..], -100_000)));
+        assert_eq!(super::year(b"-0333-"), Ok((&b"-"[..], -333)));
+    }
+
+    #[test]
+    fn expanded_year() {
+        assert_eq!(super::expanded_year(6)(b"+012018"), Ok((&[][..], 12_018)));
+        assert_eq!(super::expanded_year(6)(b"-012018"), Ok((&[][..], -12_018)));
+        assert!(super::expanded_year(6)(b"012018").is_err());
+        assert!(super::expanded_year(6)(b"+2018").is_err());
+        assert!(super::expanded_year(6)(b"+0120180").is_err());
+        assert!(super::expanded_year(10)(b"+2147483648").is_err());
+    }
+
+    #[test]
+    fn agreed_year() {
+        assert_eq!(super::agreed_year(b"+012018", 2), Ok((&[][..], 12_018)));
+        assert_eq!(super::agreed_year(b"-0120180", 2), Ok((&b"0"[..], -12_018)));
+        assert_eq!(super::agreed_year(b"+2018", 0), Ok((&[][..], 2018)));
+        assert!(super::agreed_year(b"012018", 2).is_err());
+        assert!(super::agreed_year(b"+2018", 2).is_err());
+        assert!(super::agreed_year(b"+2147483648", 6).is_err());
+        assert!(super::agreed_year(b"+00000000000000000001", 16).is_err());
+        assert!(super::agreed_year(b"+2018", usize::MAX).is_err());
+    }
+
+    #[test]
+    fn date_expanded() {
+        let ymd = YmdDate {
+            year: 12_024,
+            month: 7,
+            day: 4,
+        };
+        for s in [&b"+012024-07-04"[..], b"+0120240704"] {
+            assert_eq!(super::date_expanded(2)(s), Ok((&[][..], Date::YMD(ymd))));
+        }
+        let wd = WdDate {
+            year: -12_024,
+            week: 27,
+            day: 4,
+        };
+        for s in [&b"-012024-W27-4"[..], b"-012024W274"] {
+            assert_eq!(super::date_expanded(2)(s), Ok((&[][..], Date::WD(wd))));
+        }
+        let o = ODate {
+            year: 12_024,
+            day: 186,
+        };
+        for s in [&b"+012024-186"[..], b"+012024186"] {
+            assert_eq!(super::date_expanded(2)(s), Ok((&[][..], Date::O(o))));
+        }
+        assert!(super::date_expanded(2)(b"2024-07-04").is_err());
+        assert!(super::date_expanded(2)(b"+12024-07-04").is_err());
+        assert!(super::date_expanded(1)(b"+0120240704").is_err());
     }
 
     #[test]
@@ -292,6 +498,15 @@ mod tests {
             );
             assert_eq!(super::date_ymd(b"-03330611"), Ok((&[][..], value)));
         }
+        {
+            let value = YmdDate {
+                year: 100_000_000,
+                month: 1,
+                day: 1,
+            };
+            assert_eq!(super::date_ymd(b"+0100000000-01-01"), Ok((&[][..], value)));
+            assert!(super::date_ymd(b"+01000000000101").is_err());
+        }
         assert_eq!(
             super::date_ymd(b"2016-02-29"),
             Ok((
@@ -332,6 +547,10 @@ mod tests {
     #[test]
     fn date_y() {
         assert_eq!(super::date_y(b"2016"), Ok((&[][..], YDate { year: 2016 })));
+        assert_eq!(
+            super::date_y(b"-0000100000"),
+            Ok((&[][..], YDate { year: -100_000 }))
+        );
     }
 
     #[test]
@@ -471,6 +690,10 @@ mod tests {
             assert_eq!(super::date_approx(b"2000 "), Ok((&b" "[..], value.clone())));
             assert_eq!(super::date_approx(b"2000"), Ok((&[][..], value)));
         }
+        {
+            let value = ApproxDate::Y(YDate { year: 100_000_000 });
+            assert_eq!(super::date_approx(b"+0100000000"), Ok((&[][..], value)));
+        }
         {
             let value = ApproxDate::C(CDate { century: 20 });
             assert_eq!(super::date_approx(b"20 "), Ok((&b" "[..], value.clone())));
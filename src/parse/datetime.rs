@@ -11,7 +11,7 @@ use nom::{
 macro_rules! datetime {
     (pub $name:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
         #[inline]
-        pub fn $name(i: &[u8]) -> ParseResult<DateTime<$date, $time>> {
+        pub fn $name(i: &[u8]) -> ParseResult<'_, DateTime<$date, $time>> {
             map(
                 tuple(($date_parser, char('T'), peek(not(char('T'))), $time_parser)),
                 |(date, _, _, time)| DateTime { date, time },
@@ -45,14 +45,35 @@ datetime!(pub datetime_approx_global_approx, ApproxDate, date_approx, ApproxGlob
 datetime!(pub datetime_approx_local_approx,  ApproxDate, date_approx, ApproxLocalTime,     time_local_approx);
 datetime!(pub datetime_approx_any_approx,    ApproxDate, date_approx, ApproxAnyTime,       time_any_approx);
 
+#[inline]
+fn datetime_any_hms_format(
+    i: &[u8],
+    extended: bool,
+) -> ParseResult<'_, DateTime<Date, AnyTime<HmsTime>>> {
+    map(
+        tuple((
+            |i| date_format(i, extended),
+            char('T'),
+            peek(not(char('T'))),
+            |i| time_any_hms_format(i, extended),
+        )),
+        |(date, _, _, time)| DateTime { date, time },
+    )(i)
+}
+
+#[inline]
+pub fn datetime_strict(i: &[u8]) -> ParseResult<'_, DateTime<Date, AnyTime<HmsTime>>> {
+    strict(datetime_any_hms_format)(i)
+}
+
 pub fn partial_datetime_approx_any_approx(
     i: &[u8],
-) -> ParseResult<PartialDateTime<ApproxDate, ApproxAnyTime>> {
+) -> ParseResult<'_, PartialDateTime<ApproxDate, ApproxAnyTime>> {
     map_res(
         tuple((
             cond(
                 (!i.is_empty() && (&i[1..]).find_token('T'))
-                    || (i.get(0) != Some(&b'T') && !i.find_token(':')),
+                    || (i.first() != Some(&b'T') && !i.find_token(':')),
                 opt(date_approx),
             ),
             opt(complete(char('T'))),
@@ -68,14 +89,49 @@ pub fn partial_datetime_approx_any_approx(
     )(i)
 }
 
+pub fn partial_datetime_any_hms(
+    i: &[u8],
+) -> ParseResult<'_, PartialDateTime<Date, AnyTime<HmsTime>>> {
+    map_res(
+        tuple((
+            cond(
+                (!i.is_empty() && (&i[1..]).find_token('T'))
+                    || (i.first() != Some(&b'T') && !i.find_token(':')),
+                opt(date),
+            ),
+            opt(complete(char('T'))),
+            opt(complete(peek(not(char('T'))))),
+            opt(time_any_hms),
+        )),
+        |(d, _, _, time)| match (d.flatten(), time) {
+            (None, None) => Err(nom::Err::<&[u8]>::Incomplete(nom::Needed::Unknown)),
+            (Some(date), None) => Ok(PartialDateTime::Date(date)),
+            (None, Some(time)) => Ok(PartialDateTime::Time(time)),
+            (Some(date), Some(time)) => Ok(PartialDateTime::DateTime(DateTime { date, time })),
+        },
+    )(i)
+}
+
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
 
     #[test]
-    #[should_panic]
     fn tt() {
-        datetime_approx_any_approx(b"2018-08-02TT22:01:39Z").unwrap();
+        assert!(matches!(
+            datetime_approx_any_approx(b"2018-08-02TT22:01:39Z"),
+            Err(nom::Err::Error(_))
+        ));
+        assert!(matches!(
+            datetime_global_hms(b"2018-08-02TT22:01:39Z"),
+            Err(nom::Err::Error(_))
+        ));
+        assert!(matches!(
+            "2018-08-02TT22:01:39Z".parse::<DateTime<ApproxDate, ApproxAnyTime>>(),
+            Err(crate::Error::InvalidFormat { offset: Some(11) })
+        ));
+        assert!(datetime_approx_any_approx(b"2018-08-02T22:01:39Z").is_ok());
     }
 
     #[test]
@@ -155,7 +211,8 @@ mod tests {
                 &[][..],
                 PartialDateTime::Time(ApproxAnyTime::H(AnyTime::Local(LocalTime {
                     naive: HTime { hour: 12 },
-                    fraction: 0.,
+                    fraction: 0,
+                    fraction_digits: 0,
                 })))
             ))
         );
@@ -172,7 +229,8 @@ mod tests {
                         hour: 12,
                         minute: 30,
                     },
-                    fraction: 0.,
+                    fraction: 0,
+                    fraction_digits: 0,
                 })))
             ))
         );
@@ -185,7 +243,8 @@ mod tests {
                 hour: 12,
                 minute: 30,
             },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         })));
 
         assert_eq!(
@@ -210,7 +269,8 @@ mod tests {
                         minute: 30,
                         second: 15,
                     },
-                    fraction: 0.,
+                    fraction: 0,
+                    fraction_digits: 0,
                 })))
             ))
         );
@@ -224,7 +284,8 @@ mod tests {
                 minute: 30,
                 second: 15,
             },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         })));
 
         assert_eq!(
@@ -249,7 +310,8 @@ mod tests {
                         minute: 30,
                         second: 15,
                     },
-                    fraction: 0.2,
+                    fraction: 200_000_000,
+                    fraction_digits: 0,
                 })))
             ))
         );
@@ -263,7 +325,8 @@ mod tests {
                 minute: 30,
                 second: 15,
             },
-            fraction: 0.2,
+            fraction: 200_000_000,
+            fraction_digits: 0,
         })));
 
         assert_eq!(
@@ -290,7 +353,8 @@ mod tests {
                     minute: 30,
                     second: 15,
                 },
-                fraction: 0.2,
+                fraction: 200_000_000,
+                fraction_digits: 0,
             })),
         });
 
@@ -303,4 +367,43 @@ mod tests {
             Ok((&[][..], result))
         );
     }
+
+    #[test]
+    fn partial_datetime_any_hms() {
+        let date = Date::YMD(YmdDate {
+            year: 2018,
+            month: 8,
+            day: 2,
+        });
+        let time = AnyTime::Global(GlobalTime {
+            local: LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 15,
+                },
+                fraction: 0,
+                fraction_digits: 0,
+            },
+            timezone: Timezone::Utc,
+        });
+
+        assert_eq!(
+            super::partial_datetime_any_hms(b"2018-08-02"),
+            Ok((&[][..], PartialDateTime::Date(date)))
+        );
+        assert_eq!(
+            super::partial_datetime_any_hms(b"T12:30:15Z"),
+            Ok((&[][..], PartialDateTime::Time(time)))
+        );
+        assert_eq!(
+            super::partial_datetime_any_hms(b"12:30:15Z"),
+            Ok((&[][..], PartialDateTime::Time(time)))
+        );
+        assert_eq!(
+            super::partial_datetime_any_hms(b"2018-08-02T12:30:15Z"),
+            Ok((&[][..], PartialDateTime::DateTime(DateTime { date, time })))
+        );
+        assert!(super::partial_datetime_any_hms(b"2018-08").is_err());
+    }
 }
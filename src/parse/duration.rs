@@ -0,0 +1,117 @@
+use super::*;
+use crate::duration::*;
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{complete, map, map_opt, map_res, opt},
+    sequence::{pair, preceded, terminated, tuple},
+};
+
+#[inline]
+pub(super) fn value(i: &[u8]) -> ParseResult<'_, u32> {
+    map_res(digit1, |digits: &[u8]| {
+        digits
+            .iter()
+            .try_fold(0u32, |n, digit| {
+                n.checked_mul(10)?.checked_add((digit - b'0') as u32)
+            })
+            .ok_or(())
+    })(i)
+}
+
+#[inline]
+fn component(designator: char) -> impl FnMut(&[u8]) -> ParseResult<'_, u32> {
+    move |i| terminated(value, char(designator))(i)
+}
+
+#[inline]
+fn seconds(i: &[u8]) -> ParseResult<'_, (u32, u32)> {
+    map(
+        terminated(pair(value, opt(complete(frac_billionths))), char('S')),
+        |(seconds, fraction)| (seconds, fraction.map_or(0, |(fraction, _)| fraction)),
+    )(i)
+}
+
+/// Durations in the format with designators, `PnYnMnDTnHnMnS` or `PnW`.
+/// Combining weeks with other components is left to [`Valid`](crate::Valid).
+pub fn duration(i: &[u8]) -> ParseResult<'_, Duration> {
+    map_opt(
+        preceded(
+            char('P'),
+            pair(
+                tuple((
+                    opt(complete(component('Y'))),
+                    opt(complete(component('M'))),
+                    opt(complete(component('W'))),
+                    opt(complete(component('D'))),
+                )),
+                opt(complete(preceded(
+                    char('T'),
+                    tuple((
+                        opt(complete(component('H'))),
+                        opt(complete(component('M'))),
+                        opt(complete(seconds)),
+                    )),
+                ))),
+            ),
+        ),
+        |((years, months, weeks, days), time)| {
+            // A lone `P` or `T` designates nothing
+            match time {
+                Some((None, None, None)) => return None,
+                None if (years, months, weeks, days) == (None, None, None, None) => return None,
+                _ => (),
+            }
+            let (hours, minutes, seconds) = time.unwrap_or_default();
+
+            Some(Duration {
+                years: years.unwrap_or(0),
+                months: months.unwrap_or(0),
+                weeks: weeks.unwrap_or(0),
+                days: days.unwrap_or(0),
+                hours: hours.unwrap_or(0),
+                minutes: minutes.unwrap_or(0),
+                seconds: seconds.map_or(0, |(seconds, _)| seconds),
+                fraction: seconds.map_or(0, |(_, fraction)| fraction),
+            })
+        },
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn duration() {
+        let (rest, duration) = super::duration(b"P3Y6M4DT12H30M5.5S").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(
+            duration,
+            super::Duration {
+                years: 3,
+                months: 6,
+                weeks: 0,
+                days: 4,
+                hours: 12,
+                minutes: 30,
+                seconds: 5,
+                fraction: 500_000_000,
+            }
+        );
+
+        let (rest, duration) = super::duration(b"PT36H").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(duration.hours, 36);
+
+        let (rest, duration) = super::duration(b"P1M").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!((duration.months, duration.minutes), (1, 0));
+
+        let (rest, duration) = super::duration(b"PT1M").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!((duration.months, duration.minutes), (0, 1));
+
+        assert!(super::duration(b"P").is_err());
+        assert!(super::duration(b"PT").is_err());
+        assert!(super::duration(b"P1DT").is_err());
+        assert!(super::duration(b"P99999999999Y").is_err());
+    }
+}
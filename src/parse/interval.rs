@@ -0,0 +1,114 @@
+use super::*;
+use crate::{date::*, datetime::*, interval::*, time::*};
+use nom::{
+    character::complete::char,
+    combinator::{complete, map, opt, success},
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
+
+/// Date and time, or a date alone standing for the start of its day
+#[inline]
+fn endpoint(i: &[u8]) -> ParseResult<'_, DateTime<Date, AnyTime>> {
+    alt((
+        complete(map(datetime_any_approx, |datetime| DateTime {
+            date: datetime.date,
+            time: datetime.time.into(),
+        })),
+        map(date, |date| DateTime {
+            date,
+            time: AnyTime::Local(LocalTime {
+                naive: HmsTime {
+                    hour: 0,
+                    minute: 0,
+                    second: 0,
+                },
+                fraction: 0,
+                fraction_digits: 0,
+            }),
+        }),
+    ))(i)
+}
+
+/// End of a `start/end` interval, whose omitted higher order
+/// components are taken from the start: `MM-DDThh:mm`, `DDThh:mm` or `hh:mm`
+#[inline]
+fn abbreviated_end(i: &[u8]) -> ParseResult<'_, (Option<u8>, Option<u8>, AnyTime)> {
+    map(
+        alt((
+            complete(tuple((
+                map(terminated(month, char('-')), Some),
+                map(terminated(day, char('T')), Some),
+                time_any_approx,
+            ))),
+            complete(tuple((
+                success(None),
+                map(terminated(day, char('T')), Some),
+                time_any_approx,
+            ))),
+            complete(tuple((
+                success(None),
+                success(None),
+                preceded(opt(char('T')), time_any_approx),
+            ))),
+        )),
+        |(month, day, time)| (month, day, time.into()),
+    )(i)
+}
+
+fn start_end(i: &[u8]) -> ParseResult<'_, (DateTime<Date, AnyTime>, DateTime<Date, AnyTime>)> {
+    let (i, start) = terminated(endpoint, char('/'))(i)?;
+    let (i, end) = alt((
+        complete(endpoint),
+        complete(map(abbreviated_end, |(month, day, time)| {
+            let date = match (month, day) {
+                (None, None) => start.date,
+                (month, day) => match crate::to_calendar_date(start.date) {
+                    Ok(ymd) => Date::YMD(YmdDate {
+                        year: ymd.year,
+                        month: month.unwrap_or(ymd.month),
+                        day: day.unwrap_or(ymd.day),
+                    }),
+                    // the invalid start fails validation afterwards
+                    Err(_) => start.date,
+                },
+            };
+            let time = match (start.time, time) {
+                (AnyTime::Global(start), AnyTime::Local(local)) => AnyTime::Global(GlobalTime {
+                    local,
+                    timezone: start.timezone,
+                }),
+                (_, time) => time,
+            };
+            DateTime { date, time }
+        })),
+    ))(i)?;
+    Ok((i, (start, end)))
+}
+
+/// Intervals as `start/end`, `start/duration` or `duration/end` (4.4.4)
+pub fn interval(i: &[u8]) -> ParseResult<'_, Interval> {
+    alt((
+        complete(map(start_end, |(start, end)| {
+            Interval::StartEnd(start, end)
+        })),
+        complete(map(
+            separated_pair(endpoint, char('/'), duration),
+            |(start, duration)| Interval::StartDuration(start, duration),
+        )),
+        complete(map(
+            separated_pair(duration, char('/'), endpoint),
+            |(duration, end)| Interval::DurationEnd(duration, end),
+        )),
+    ))(i)
+}
+
+/// Repeating intervals, `Rn/` or `R/` followed by an interval (4.5)
+pub fn recurring_interval(i: &[u8]) -> ParseResult<'_, RecurringInterval> {
+    map(
+        tuple((char('R'), opt(value), char('/'), interval)),
+        |(_, repetitions, _, interval)| RecurringInterval {
+            repetitions,
+            interval,
+        },
+    )(i)
+}
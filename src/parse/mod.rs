@@ -1,19 +1,19 @@
 mod date;
 mod datetime;
+mod duration;
+mod interval;
 mod time;
 
-pub use self::{date::*, datetime::*, time::*};
+pub use self::{date::*, datetime::*, duration::*, interval::*, time::*};
 
+use core::ops::{AddAssign, MulAssign};
 use nom::{
     self,
     branch::alt,
-    character::streaming::char,
-    character::streaming::one_of,
-    combinator::{map, map_parser, peek},
-    number::complete::{float, recognize_float},
+    character::complete::{char, digit1, one_of},
+    combinator::{all_consuming, map},
     sequence::preceded,
 };
-use std::ops::{AddAssign, MulAssign};
 
 pub(crate) type ParseResult<'a, T> = nom::IResult<&'a [u8], T>;
 
@@ -31,27 +31,68 @@ where
 }
 
 #[inline]
-fn sign(i: &[u8]) -> ParseResult<i8> {
+fn sign(i: &[u8]) -> ParseResult<'_, i8> {
     alt((
         map(one_of("-\u{2212}\u{2010}"), |_| -1),
         map(char('+'), |_| 1),
     ))(i)
 }
 
+/// Decimal fraction in billionths and its number of digits, with either
+/// a comma or a full stop as the decimal sign (4.2.2.4).
+/// Digits past the ninth are dropped.
 #[inline]
-fn frac32(i: &[u8]) -> ParseResult<f32> {
-    preceded(peek(char('.')), map_parser(recognize_float, float))(i)
+fn frac_billionths(i: &[u8]) -> ParseResult<'_, (u32, u8)> {
+    map(preceded(one_of(".,"), digit1), |digits: &[u8]| {
+        let fraction = digits
+            .iter()
+            .chain(core::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |fraction, digit| fraction * 10 + (digit - b'0') as u32);
+        (fraction, digits.len().min(9) as u8)
+    })(i)
+}
+
+/// Builds an [`InvalidFormat`](crate::Error::InvalidFormat) error pointing at
+/// the unparsed remainder of `input`, or at its end if more input was needed
+pub(crate) fn invalid_format(
+    input: &[u8],
+    err: nom::Err<nom::error::Error<&[u8]>>,
+) -> crate::Error {
+    let offset = match err {
+        nom::Err::Incomplete(_) => input.len(),
+        nom::Err::Error(err) | nom::Err::Failure(err) => input.len() - err.input.len(),
+    };
+    crate::Error::InvalidFormat {
+        offset: Some(offset),
+    }
+}
+
+/// Commits to either the extended or the basic format for the
+/// whole input, instead of settling each component separately.
+/// On failure, reports the format that got further into the input.
+#[inline]
+fn strict<'a, O>(
+    parser: impl Fn(&'a [u8], bool) -> ParseResult<'a, O> + Copy,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, O> {
+    move |i| match all_consuming(move |i| parser(i, true))(i) {
+        Err(nom::Err::Error(extended)) => {
+            all_consuming(move |i| parser(i, false))(i).map_err(|err| match err {
+                nom::Err::Error(basic) if basic.input.len() > extended.input.len() => {
+                    nom::Err::Error(extended)
+                }
+                err => err,
+            })
+        }
+        res => res,
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use {
-        nom::{
-            error::{Error, ErrorKind::Char},
-            Err,
-            Needed::Size,
-        },
-        std::num::NonZeroUsize,
+    use nom::{
+        error::{Error, ErrorKind::Char},
+        Err,
     };
 
     #[test]
@@ -60,7 +101,10 @@ mod tests {
         assert_eq!(super::sign(b"+"), Ok((&[][..], 1)));
         assert_eq!(
             super::sign(b""),
-            Err(Err::Incomplete(Size(NonZeroUsize::new(1).unwrap())))
+            Err(Err::Error(Error {
+                input: &b""[..],
+                code: Char
+            }))
         );
         assert_eq!(
             super::sign(b" "),
@@ -70,4 +114,59 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn never_incomplete() {
+        for (_, example) in crate::FORMATS {
+            for len in 0..=example.len() {
+                let input = &example.as_bytes()[..len];
+                assert!(
+                    !matches!(
+                        super::partial_datetime_approx_any_approx(input),
+                        Err(Err::Incomplete(_))
+                    ),
+                    "{:?}",
+                    core::str::from_utf8(input)
+                );
+            }
+        }
+        assert!(matches!(
+            "+".parse::<crate::Timezone>(),
+            Err(crate::Error::InvalidFormat { offset: Some(1) })
+        ));
+        assert!(matches!(
+            "-".parse::<crate::Date>(),
+            Err(crate::Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn frac_billionths() {
+        assert_eq!(
+            super::frac_billionths(b".5"),
+            Ok((&[][..], (500_000_000, 1)))
+        );
+        assert_eq!(
+            super::frac_billionths(b",5"),
+            Ok((&[][..], (500_000_000, 1)))
+        );
+        assert_eq!(
+            super::frac_billionths(b",250Z"),
+            Ok((&b"Z"[..], (250_000_000, 3)))
+        );
+        assert_eq!(
+            super::frac_billionths(b".123456789"),
+            Ok((&[][..], (123_456_789, 9)))
+        );
+        assert_eq!(
+            super::frac_billionths(b".0000000019"),
+            Ok((&[][..], (1, 9)))
+        );
+        assert_eq!(
+            super::frac_billionths(&[b"."[..].as_ref(), &[b'9'; 100]].concat()),
+            Ok((&[][..], (999_999_999, 9)))
+        );
+        assert!(super::frac_billionths(b",").is_err());
+        assert!(super::frac_billionths(b"5").is_err());
+    }
 }
@@ -5,27 +5,27 @@ use nom::{
     bytes::complete::take_while_m_n,
     character::complete::char,
     character::is_digit,
-    combinator::{complete, cond, map, opt},
+    combinator::{complete, cond, map, opt, verify},
     sequence::{pair, preceded, separated_pair, tuple},
 };
 
 #[inline]
-fn hour(i: &[u8]) -> ParseResult<u8> {
+fn hour(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn minute(i: &[u8]) -> ParseResult<u8> {
+fn minute(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn second(i: &[u8]) -> ParseResult<u8> {
+fn second(i: &[u8]) -> ParseResult<'_, u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
 #[inline]
-fn time_hms_format(i: &[u8], extended: bool) -> ParseResult<HmsTime> {
+fn time_hms_format(i: &[u8], extended: bool) -> ParseResult<'_, HmsTime> {
     map(
         tuple((
             hour,
@@ -43,22 +43,22 @@ fn time_hms_format(i: &[u8], extended: bool) -> ParseResult<HmsTime> {
 }
 
 #[inline]
-fn time_hms_basic(i: &[u8]) -> ParseResult<HmsTime> {
+fn time_hms_basic(i: &[u8]) -> ParseResult<'_, HmsTime> {
     time_hms_format(i, false)
 }
 
 #[inline]
-fn time_hms_extended(i: &[u8]) -> ParseResult<HmsTime> {
+fn time_hms_extended(i: &[u8]) -> ParseResult<'_, HmsTime> {
     time_hms_format(i, true)
 }
 
 #[inline]
-pub fn time_hms(i: &[u8]) -> ParseResult<HmsTime> {
+pub fn time_hms(i: &[u8]) -> ParseResult<'_, HmsTime> {
     alt((time_hms_extended, time_hms_basic))(i)
 }
 
 #[inline]
-fn time_hm_format(i: &[u8], extended: bool) -> ParseResult<HmTime> {
+fn time_hm_format(i: &[u8], extended: bool) -> ParseResult<'_, HmTime> {
     map(
         separated_pair(hour, cond(extended, char(':')), minute),
         |(hour, minute)| HmTime { hour, minute },
@@ -66,27 +66,27 @@ fn time_hm_format(i: &[u8], extended: bool) -> ParseResult<HmTime> {
 }
 
 #[inline]
-fn time_hm_basic(i: &[u8]) -> ParseResult<HmTime> {
+fn time_hm_basic(i: &[u8]) -> ParseResult<'_, HmTime> {
     time_hm_format(i, false)
 }
 
 #[inline]
-fn time_hm_extended(i: &[u8]) -> ParseResult<HmTime> {
+fn time_hm_extended(i: &[u8]) -> ParseResult<'_, HmTime> {
     time_hm_format(i, true)
 }
 
 #[inline]
-pub fn time_hm(i: &[u8]) -> ParseResult<HmTime> {
+pub fn time_hm(i: &[u8]) -> ParseResult<'_, HmTime> {
     alt((time_hm_extended, time_hm_basic))(i)
 }
 
 #[inline]
-pub fn time_h(i: &[u8]) -> ParseResult<HTime> {
+pub fn time_h(i: &[u8]) -> ParseResult<'_, HTime> {
     map(hour, |hour| HTime { hour })(i)
 }
 
 #[inline]
-fn time_naive_approx(i: &[u8]) -> ParseResult<ApproxNaiveTime> {
+fn time_naive_approx(i: &[u8]) -> ParseResult<'_, ApproxNaiveTime> {
     alt((
         complete(map(time_hms, ApproxNaiveTime::HMS)),
         complete(map(time_hm, ApproxNaiveTime::HM)),
@@ -95,28 +95,34 @@ fn time_naive_approx(i: &[u8]) -> ParseResult<ApproxNaiveTime> {
 }
 
 #[inline]
-pub fn time_local_approx(i: &[u8]) -> ParseResult<ApproxLocalTime> {
+pub fn time_local_approx(i: &[u8]) -> ParseResult<'_, ApproxLocalTime> {
     map(
-        pair(time_naive_approx, opt(complete(frac32))),
-        |(naive, fraction)| match naive {
-            ApproxNaiveTime::HMS(naive) => ApproxLocalTime::HMS(LocalTime {
-                naive,
-                fraction: fraction.unwrap_or(0.),
-            }),
-            ApproxNaiveTime::HM(naive) => ApproxLocalTime::HM(LocalTime {
-                naive,
-                fraction: fraction.unwrap_or(0.),
-            }),
-            ApproxNaiveTime::H(naive) => ApproxLocalTime::H(LocalTime {
-                naive,
-                fraction: fraction.unwrap_or(0.),
-            }),
+        pair(time_naive_approx, opt(complete(frac_billionths))),
+        |(naive, fraction)| {
+            let (fraction, fraction_digits) = fraction.unwrap_or((0, 0));
+            match naive {
+                ApproxNaiveTime::HMS(naive) => ApproxLocalTime::HMS(LocalTime {
+                    naive,
+                    fraction,
+                    fraction_digits,
+                }),
+                ApproxNaiveTime::HM(naive) => ApproxLocalTime::HM(LocalTime {
+                    naive,
+                    fraction,
+                    fraction_digits,
+                }),
+                ApproxNaiveTime::H(naive) => ApproxLocalTime::H(LocalTime {
+                    naive,
+                    fraction,
+                    fraction_digits,
+                }),
+            }
         },
     )(i)
 }
 
 #[inline]
-pub fn time_global_approx(i: &[u8]) -> ParseResult<ApproxGlobalTime> {
+pub fn time_global_approx(i: &[u8]) -> ParseResult<'_, ApproxGlobalTime> {
     map(
         pair(time_local_approx, timezone),
         |(local, timezone)| match local {
@@ -128,7 +134,7 @@ pub fn time_global_approx(i: &[u8]) -> ParseResult<ApproxGlobalTime> {
 }
 
 #[inline]
-pub fn time_any_approx(i: &[u8]) -> ParseResult<ApproxAnyTime> {
+pub fn time_any_approx(i: &[u8]) -> ParseResult<'_, ApproxAnyTime> {
     alt((
         map(time_any_hms, ApproxAnyTime::HMS),
         map(time_any_hm, ApproxAnyTime::HM),
@@ -139,12 +145,20 @@ pub fn time_any_approx(i: &[u8]) -> ParseResult<ApproxAnyTime> {
 macro_rules! time_local_accuracy {
     (pub $name:ident, $naive:ty, $naive_submac:ident) => {
         #[inline]
-        pub fn $name(i: &[u8]) -> ParseResult<LocalTime<$naive>> {
+        pub fn $name(i: &[u8]) -> ParseResult<'_, LocalTime<$naive>> {
             map(
-                tuple((opt(char('T')), $naive_submac, opt(complete(frac32)))),
-                |(_, naive, fraction)| LocalTime {
-                    naive,
-                    fraction: fraction.unwrap_or(0.),
+                tuple((
+                    opt(char('T')),
+                    $naive_submac,
+                    opt(complete(frac_billionths)),
+                )),
+                |(_, naive, fraction)| {
+                    let (fraction, fraction_digits) = fraction.unwrap_or((0, 0));
+                    LocalTime {
+                        naive,
+                        fraction,
+                        fraction_digits,
+                    }
                 },
             )(i)
         }
@@ -158,7 +172,7 @@ time_local_accuracy!(pub time_local_h,   HTime,   time_h);
 macro_rules! time_global_accuracy {
     (pub $name:ident, $naive:ty, $local_submac:ident) => {
         #[inline]
-        pub fn $name(i: &[u8]) -> ParseResult<GlobalTime<$naive>> {
+        pub fn $name(i: &[u8]) -> ParseResult<'_, GlobalTime<$naive>> {
             map(
                 pair($local_submac, complete(timezone)),
                 |(local, timezone)| GlobalTime { local, timezone },
@@ -173,7 +187,7 @@ time_global_accuracy!(pub time_global_h,   HTime,   time_local_h);
 macro_rules! time_any_accuracy {
     (pub $name:ident, $naive:ty, $local_submac:ident, $global_submac:ident) => {
         #[inline]
-        pub fn $name(i: &[u8]) -> ParseResult<AnyTime<$naive>> {
+        pub fn $name(i: &[u8]) -> ParseResult<'_, AnyTime<$naive>> {
             alt((
                 complete(map($global_submac, AnyTime::Global)),
                 complete(map($local_submac, AnyTime::Local)),
@@ -186,24 +200,124 @@ time_any_accuracy!(pub time_any_hm,  HmTime,  time_local_hm,  time_global_hm);
 time_any_accuracy!(pub time_any_h,   HTime,   time_local_h,   time_global_h);
 
 #[inline]
-fn timezone_utc(i: &[u8]) -> ParseResult<i16> {
-    map(char('Z'), |_| 0)(i)
+pub fn time_global_hms_strict_tz(i: &[u8]) -> ParseResult<'_, StrictTimezone> {
+    map(
+        verify(time_global_hms, |time| time.timezone.minutes() % 15 == 0),
+        StrictTimezone,
+    )(i)
+}
+
+#[inline]
+fn time_local_hms_format(i: &[u8], extended: bool) -> ParseResult<'_, LocalTime<HmsTime>> {
+    map(
+        tuple((
+            opt(char('T')),
+            |i| time_hms_format(i, extended),
+            opt(complete(frac_billionths)),
+        )),
+        |(_, naive, fraction)| {
+            let (fraction, fraction_digits) = fraction.unwrap_or((0, 0));
+            LocalTime {
+                naive,
+                fraction,
+                fraction_digits,
+            }
+        },
+    )(i)
+}
+
+#[inline]
+pub(super) fn time_any_hms_format(i: &[u8], extended: bool) -> ParseResult<'_, AnyTime<HmsTime>> {
+    alt((
+        complete(map(
+            pair(
+                |i| time_local_hms_format(i, extended),
+                complete(|i| timezone_format(i, extended)),
+            ),
+            |(local, timezone)| AnyTime::Global(GlobalTime { local, timezone }),
+        )),
+        complete(map(|i| time_local_hms_format(i, extended), AnyTime::Local)),
+    ))(i)
+}
+
+#[inline]
+pub fn time_strict(i: &[u8]) -> ParseResult<'_, AnyTime<HmsTime>> {
+    strict(time_any_hms_format)(i)
+}
+
+#[inline]
+fn timezone_utc(i: &[u8]) -> ParseResult<'_, Timezone> {
+    map(char('Z'), |_| Timezone::Utc)(i)
 }
 
+/// Two digit offset component of at most `max`. Digits out of range are
+/// a failure, so that they are not left as trailing input after a local time.
 #[inline]
-fn timezone_fixed(i: &[u8]) -> ParseResult<i16> {
+fn offset_component<'a>(
+    digits: fn(&'a [u8]) -> ParseResult<'a, u8>,
+    max: u8,
+) -> impl Fn(&'a [u8]) -> ParseResult<'a, u8> {
+    move |i| match digits(i)? {
+        (rest, value) if value <= max => Ok((rest, value)),
+        _ => Err(nom::Err::Failure(nom::error::Error::new(
+            i,
+            nom::error::ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// A negative zero offset is kept apart as [`Timezone::Unknown`]
+#[inline]
+fn offset(sign: i8, hour: u8, minute: Option<u8>) -> Timezone {
+    let minutes = hour as i16 * 60 + minute.unwrap_or(0) as i16;
+    if sign < 0 && minutes == 0 {
+        Timezone::Unknown
+    } else {
+        Timezone::Fixed(sign as i16 * minutes)
+    }
+}
+
+#[inline]
+fn timezone_fixed(i: &[u8]) -> ParseResult<'_, Timezone> {
     map(
-        tuple((sign, hour, opt(complete(preceded(opt(char(':')), minute))))),
-        |(sign, hour, minute)| sign as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16),
+        tuple((
+            sign,
+            offset_component(hour, 23),
+            opt(complete(preceded(
+                opt(char(':')),
+                offset_component(minute, 59),
+            ))),
+        )),
+        |(sign, hour, minute)| offset(sign, hour, minute),
     )(i)
 }
 
 #[inline]
-fn timezone(i: &[u8]) -> ParseResult<i16> {
+fn timezone_format(i: &[u8], extended: bool) -> ParseResult<'_, Timezone> {
+    alt((
+        timezone_utc,
+        map(
+            tuple((
+                sign,
+                offset_component(hour, 23),
+                opt(complete(preceded(
+                    cond(extended, char(':')),
+                    offset_component(minute, 59),
+                ))),
+            )),
+            |(sign, hour, minute)| offset(sign, hour, minute),
+        ),
+    ))(i)
+}
+
+/// Standalone difference from UTC, either `Z` or `±hh[[:]mm]`
+#[inline]
+pub fn timezone(i: &[u8]) -> ParseResult<'_, Timezone> {
     alt((timezone_utc, timezone_fixed))(i)
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
     use nom::{
@@ -235,25 +349,34 @@ mod tests {
     fn timezone_fixed() {
         assert_eq!(
             super::timezone_fixed(b"+23:59 "),
-            Ok((&b" "[..], 23 * 60 + 59))
+            Ok((&b" "[..], Timezone::Fixed(23 * 60 + 59)))
         );
         assert_eq!(
             super::timezone_fixed(b"+23:59"),
-            Ok((&[][..], 23 * 60 + 59))
+            Ok((&[][..], Timezone::Fixed(23 * 60 + 59)))
         );
         assert_eq!(
             super::timezone_fixed(b"+2359 "),
-            Ok((&b" "[..], 23 * 60 + 59))
+            Ok((&b" "[..], Timezone::Fixed(23 * 60 + 59)))
+        );
+        assert_eq!(
+            super::timezone_fixed(b"+2359"),
+            Ok((&[][..], Timezone::Fixed(23 * 60 + 59)))
+        );
+        assert_eq!(
+            super::timezone_fixed(b"-23 "),
+            Ok((&b" "[..], Timezone::Fixed(-23 * 60)))
+        );
+        assert_eq!(
+            super::timezone_fixed(b"-23"),
+            Ok((&[][..], Timezone::Fixed(-23 * 60)))
         );
-        assert_eq!(super::timezone_fixed(b"+2359"), Ok((&[][..], 23 * 60 + 59)));
-        assert_eq!(super::timezone_fixed(b"-23 "), Ok((&b" "[..], -23 * 60)));
-        assert_eq!(super::timezone_fixed(b"-23"), Ok((&[][..], -23 * 60)));
     }
 
     #[test]
     fn timezone_utc() {
-        assert_eq!(super::timezone_utc(b"Z "), Ok((&b" "[..], 0)));
-        assert_eq!(super::timezone_utc(b"Z"), Ok((&[][..], 0)));
+        assert_eq!(super::timezone_utc(b"Z "), Ok((&b" "[..], Timezone::Utc)));
+        assert_eq!(super::timezone_utc(b"Z"), Ok((&[][..], Timezone::Utc)));
         assert_eq!(
             super::timezone_utc(b"z"),
             Err(Err::Error(Error {
@@ -265,12 +388,34 @@ mod tests {
 
     #[test]
     fn timezone() {
-        assert_eq!(super::timezone(b"-22:11 "), Ok((&b" "[..], -22 * 60 - 11)));
-        assert_eq!(super::timezone(b"-22:11"), Ok((&[][..], -22 * 60 - 11)));
-        assert_eq!(super::timezone(b"-2211 "), Ok((&b" "[..], -22 * 60 - 11)));
-        assert_eq!(super::timezone(b"-2211"), Ok((&[][..], -22 * 60 - 11)));
-        assert_eq!(super::timezone(b"Z "), Ok((&b" "[..], 0)));
-        assert_eq!(super::timezone(b"Z"), Ok((&[][..], 0)));
+        assert_eq!(
+            super::timezone(b"-22:11 "),
+            Ok((&b" "[..], Timezone::Fixed(-22 * 60 - 11)))
+        );
+        assert_eq!(
+            super::timezone(b"-22:11"),
+            Ok((&[][..], Timezone::Fixed(-22 * 60 - 11)))
+        );
+        assert_eq!(
+            super::timezone(b"-2211 "),
+            Ok((&b" "[..], Timezone::Fixed(-22 * 60 - 11)))
+        );
+        assert_eq!(
+            super::timezone(b"-2211"),
+            Ok((&[][..], Timezone::Fixed(-22 * 60 - 11)))
+        );
+        assert_eq!(super::timezone(b"Z "), Ok((&b" "[..], Timezone::Utc)));
+        assert_eq!(super::timezone(b"Z"), Ok((&[][..], Timezone::Utc)));
+        assert_eq!(
+            super::timezone(b"+00:00"),
+            Ok((&[][..], Timezone::Fixed(0)))
+        );
+        assert_eq!(super::timezone(b"-00"), Ok((&[][..], Timezone::Unknown)));
+        assert_eq!(super::timezone(b"-00:00"), Ok((&[][..], Timezone::Unknown)));
+        assert_eq!(
+            super::timezone_format(b"-0000", false),
+            Ok((&[][..], Timezone::Unknown))
+        );
     }
 
     #[test]
@@ -316,7 +461,8 @@ mod tests {
                 minute: 43,
                 second: 52,
             },
-            fraction: 0.1,
+            fraction: 100_000_000,
+            fraction_digits: 0,
         };
         assert_eq!(
             super::time_local_hms(b"T16:43:52.1 "),
@@ -338,9 +484,11 @@ mod tests {
             super::time_local_hms(b"164352.1"),
             Ok((&[][..], value.clone()))
         );
+        assert_eq!(super::time_local_hms(b"16:43:52,1"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_hms(b"T164352,1"), Ok((&[][..], value)));
 
         let value = LocalTime {
-            fraction: 0.,
+            fraction: 0,
             ..value
         };
         assert_eq!(
@@ -357,7 +505,8 @@ mod tests {
                 hour: 16,
                 minute: 43,
             },
-            fraction: 0.1,
+            fraction: 100_000_000,
+            fraction_digits: 0,
         };
         assert_eq!(
             super::time_local_hm(b"T16:43.1"),
@@ -375,9 +524,11 @@ mod tests {
             super::time_local_hm(b"1643.1"),
             Ok((&[][..], value.clone()))
         );
+        assert_eq!(super::time_local_hm(b"16:43,1"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"T1643,1"), Ok((&[][..], value)));
 
         let value = LocalTime {
-            fraction: 0.,
+            fraction: 0,
             ..value
         };
         assert_eq!(
@@ -393,13 +544,16 @@ mod tests {
     fn time_local_h() {
         let value = LocalTime {
             naive: HTime { hour: 16 },
-            fraction: 0.1,
+            fraction: 100_000_000,
+            fraction_digits: 0,
         };
         assert_eq!(super::time_local_h(b"T16.1"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_local_h(b"16.1"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_h(b"16,1"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_h(b"T16,1"), Ok((&[][..], value)));
 
         let value = LocalTime {
-            fraction: 0.,
+            fraction: 0,
             ..value
         };
         assert_eq!(super::time_local_h(b"T16"), Ok((&[][..], value.clone())));
@@ -415,9 +569,10 @@ mod tests {
                     minute: 43,
                     second: 52,
                 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         };
         assert_eq!(
             super::time_global_hms(b"T16:43:52Z"),
@@ -438,7 +593,7 @@ mod tests {
 
         {
             let value = GlobalTime {
-                timezone: 2,
+                timezone: Timezone::Fixed(2),
                 ..value.clone()
             };
             assert_eq!(
@@ -460,7 +615,7 @@ mod tests {
 
             let value = GlobalTime {
                 local: LocalTime {
-                    fraction: 0.1,
+                    fraction: 100_000_000,
                     ..value.local
                 },
                 ..value
@@ -485,7 +640,7 @@ mod tests {
 
         let value = GlobalTime {
             local: LocalTime {
-                fraction: 0.1,
+                fraction: 100_000_000,
                 ..value.local
             },
             ..value
@@ -503,6 +658,7 @@ mod tests {
             Ok((&[][..], value.clone()))
         );
         assert_eq!(super::time_global_hms(b"164352.1Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"16:43:52,1Z"), Ok((&[][..], value)));
     }
 
     #[test]
@@ -513,9 +669,10 @@ mod tests {
                     hour: 16,
                     minute: 43,
                 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         };
         assert_eq!(
             super::time_global_hm(b"T16:43Z"),
@@ -536,7 +693,7 @@ mod tests {
 
         let value = GlobalTime {
             local: LocalTime {
-                fraction: 0.1,
+                fraction: 100_000_000,
                 ..value.local
             },
             ..value
@@ -561,16 +718,17 @@ mod tests {
         let value = GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 16 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         };
         assert_eq!(super::time_global_h(b"T16Z"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_global_h(b"16Z"), Ok((&[][..], value.clone())));
 
         let value = GlobalTime {
             local: LocalTime {
-                fraction: 0.1,
+                fraction: 100_000_000,
                 ..value.local
             },
             ..value
@@ -590,7 +748,8 @@ mod tests {
                 minute: 43,
                 second: 52,
             },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         });
         assert_eq!(
             super::time_any_hms(b"T16:43:52"),
@@ -613,9 +772,10 @@ mod tests {
                     minute: 3,
                     second: 52,
                 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         });
         assert_eq!(
             super::time_any_hms(b"T02:03:52Z"),
@@ -638,9 +798,10 @@ mod tests {
                     minute: 3,
                     second: 52,
                 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: -1 * 60,
+            timezone: Timezone::Fixed(-60),
         });
         assert_eq!(
             super::time_any_hms(b"T02:03:52-01"),
@@ -664,7 +825,8 @@ mod tests {
                 hour: 16,
                 minute: 43,
             },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         });
         assert_eq!(super::time_any_hm(b"T16:43"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_any_hm(b"16:43"), Ok((&[][..], value.clone())));
@@ -674,9 +836,10 @@ mod tests {
         let value = AnyTime::Global(GlobalTime {
             local: LocalTime {
                 naive: HmTime { hour: 2, minute: 3 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         });
         assert_eq!(super::time_any_hm(b"T02:03Z"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_any_hm(b"02:03Z"), Ok((&[][..], value.clone())));
@@ -686,9 +849,10 @@ mod tests {
         let value = AnyTime::Global(GlobalTime {
             local: LocalTime {
                 naive: HmTime { hour: 2, minute: 3 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: -1 * 60,
+            timezone: Timezone::Fixed(-60),
         });
         assert_eq!(
             super::time_any_hm(b"T02:03-01"),
@@ -709,7 +873,8 @@ mod tests {
     fn time_any_h() {
         let value = AnyTime::Local(LocalTime {
             naive: HTime { hour: 16 },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         });
         assert_eq!(super::time_any_h(b"T16"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_any_h(b"16"), Ok((&[][..], value)));
@@ -717,9 +882,10 @@ mod tests {
         let value = AnyTime::Global(GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 2 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0,
+            timezone: Timezone::Utc,
         });
         assert_eq!(super::time_any_h(b"T02Z"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_any_h(b"02Z"), Ok((&[][..], value)));
@@ -727,9 +893,10 @@ mod tests {
         let value = AnyTime::Global(GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 2 },
-                fraction: 0.,
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: -1 * 60,
+            timezone: Timezone::Fixed(-60),
         });
         assert_eq!(super::time_any_h(b"T02-01"), Ok((&[][..], value.clone())));
         assert_eq!(super::time_any_h(b"02-01"), Ok((&[][..], value)));
@@ -747,7 +914,8 @@ mod tests {
                         minute: 22,
                         second: 48
                     },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 })
             ))
         );
@@ -761,7 +929,8 @@ mod tests {
                         hour: 16,
                         minute: 22
                     },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 })
             ))
         );
@@ -772,7 +941,8 @@ mod tests {
                 &[][..],
                 ApproxLocalTime::H(LocalTime {
                     naive: HTime { hour: 16 },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 })
             ))
         );
@@ -791,9 +961,10 @@ mod tests {
                             minute: 22,
                             second: 48
                         },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 })
             ))
         );
@@ -808,9 +979,10 @@ mod tests {
                             hour: 16,
                             minute: 22
                         },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 })
             ))
         );
@@ -822,14 +994,38 @@ mod tests {
                 ApproxGlobalTime::H(GlobalTime {
                     local: LocalTime {
                         naive: HTime { hour: 16 },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 })
             ))
         );
     }
 
+    #[test]
+    fn decimal_comma() {
+        for (comma, stop) in [
+            (&b"16:22:48,25"[..], &b"16:22:48.25"[..]),
+            (b"1622,25", b"1622.25"),
+            (b"16,25", b"16.25"),
+        ] {
+            assert!(matches!(super::time_local_approx(comma), Ok((&[], _))));
+            assert_eq!(
+                super::time_local_approx(comma),
+                super::time_local_approx(stop)
+            );
+        }
+        for (comma, stop) in [
+            (&b"16:22:48,25Z"[..], &b"16:22:48.25Z"[..]),
+            (b"T1622,25+0100", b"T1622.25+0100"),
+            (b"16,25-05", b"16.25-05"),
+        ] {
+            assert!(matches!(super::time_any_approx(comma), Ok((&[], _))));
+            assert_eq!(super::time_any_approx(comma), super::time_any_approx(stop));
+        }
+    }
+
     #[test]
     fn time_any_approx() {
         assert_eq!(
@@ -842,7 +1038,8 @@ mod tests {
                         minute: 22,
                         second: 48
                     },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 }))
             ))
         );
@@ -855,7 +1052,8 @@ mod tests {
                         hour: 16,
                         minute: 22
                     },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 }))
             ))
         );
@@ -865,7 +1063,8 @@ mod tests {
                 &[][..],
                 ApproxAnyTime::H(AnyTime::Local(LocalTime {
                     naive: HTime { hour: 16 },
-                    fraction: 0.
+                    fraction: 0,
+                    fraction_digits: 0,
                 }))
             ))
         );
@@ -881,9 +1080,10 @@ mod tests {
                             minute: 22,
                             second: 48
                         },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 }))
             ))
         );
@@ -897,9 +1097,10 @@ mod tests {
                             hour: 16,
                             minute: 22
                         },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 }))
             ))
         );
@@ -910,11 +1111,39 @@ mod tests {
                 ApproxAnyTime::H(AnyTime::Global(GlobalTime {
                     local: LocalTime {
                         naive: HTime { hour: 16 },
-                        fraction: 0.
+                        fraction: 0,
+                        fraction_digits: 0,
                     },
-                    timezone: 0
+                    timezone: Timezone::Utc
                 }))
             ))
         );
     }
+
+    #[test]
+    fn time_global_hms_strict_tz() {
+        assert_eq!(
+            super::time_global_hms_strict_tz(b"16:43:52+05:30")
+                .unwrap()
+                .1
+                .as_ref()
+                .timezone,
+            Timezone::Fixed(330)
+        );
+        assert!(super::time_global_hms_strict_tz(b"16:43:52+05:31").is_err());
+    }
+
+    #[test]
+    fn time_local_hms_nanosecond() {
+        let (rest, time) = super::time_local_hms(b"16:43:52.123456789").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(time.fraction, 123_456_789);
+        assert_eq!(time.nanosecond(), 123_456_789);
+
+        let (_, time) = super::time_local_hms(b"16:43:52.999999999").unwrap();
+        assert_eq!(time.nanosecond(), 999_999_999);
+
+        let (_, time) = super::time_local_hms(b"16:43:52.5").unwrap();
+        assert_eq!(time.nanosecond(), 500_000_000);
+    }
 }
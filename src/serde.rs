@@ -0,0 +1,260 @@
+#![cfg(feature = "serde")]
+use crate::{
+    AnyTime, ApproxAnyTime, ApproxDate, ApproxGlobalTime, ApproxLocalTime, CDate, Date, DateTime,
+    Datelike, Duration, Error, GlobalTime, HTime, HmTime, HmsTime, Interval, LocalTime, ODate,
+    PartialDateTime, RecurringInterval, StrictTimezone, Timelike, Timezone, Valid, WDate, WdDate,
+    YDate, YmDate, YmdDate,
+};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Parses and validates strings with the [`FromStr`] impl of `T`
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<T> de::Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr<Err = Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ISO 8601 string")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(E::custom)
+    }
+}
+
+/// Serializes as the [`Display`](fmt::Display) string
+/// and deserializes with the [`FromStr`] impl.
+macro_rules! impl_serde_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_str!(
+    Date,
+    ApproxDate,
+    YmdDate,
+    YmDate,
+    YDate,
+    CDate,
+    WdDate,
+    WDate,
+    ODate,
+    LocalTime<HmTime>,
+    LocalTime<HTime>,
+    GlobalTime<HmsTime>,
+    GlobalTime<HmTime>,
+    GlobalTime<HTime>,
+    AnyTime<HmsTime>,
+    AnyTime<HmTime>,
+    AnyTime<HTime>,
+    ApproxLocalTime,
+    ApproxGlobalTime,
+    ApproxAnyTime,
+    Timezone,
+    StrictTimezone,
+    Duration,
+    Interval,
+    RecurringInterval,
+);
+
+impl<D, T> Serialize for DateTime<D, T>
+where
+    D: Datelike + fmt::Display,
+    T: Timelike + fmt::Display,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, D, T> Deserialize<'de> for DateTime<D, T>
+where
+    D: Datelike,
+    T: Timelike,
+    Self: FromStr<Err = Error>,
+{
+    #[inline]
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData))
+    }
+}
+
+impl<D, T> Serialize for PartialDateTime<D, T>
+where
+    D: Datelike + fmt::Display,
+    T: Timelike + fmt::Display,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, D, T> Deserialize<'de> for PartialDateTime<D, T>
+where
+    D: Datelike,
+    T: Timelike,
+    Self: FromStr<Err = Error>,
+{
+    #[inline]
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData))
+    }
+}
+
+/// Serializes as the [`Display`](fmt::Display) string.
+impl Serialize for LocalTime<HmsTime> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Deserialize)]
+struct HmsTimeRepr {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LocalTimeRepr {
+    String(String),
+    Object { naive: HmsTimeRepr, fraction: f64 },
+}
+
+/// Accepts both the string notation and the
+/// `{"naive": {"hour": .., "minute": .., "second": ..}, "fraction": ..}` object form,
+/// with a fraction of a second in `[0, 1)`, which is validated like a parsed string.
+impl<'de> Deserialize<'de> for LocalTime<HmsTime> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match LocalTimeRepr::deserialize(deserializer)? {
+            LocalTimeRepr::String(s) => s.parse().map_err(de::Error::custom),
+            LocalTimeRepr::Object { naive, fraction } => {
+                if !(0. ..1.).contains(&fraction) {
+                    return Err(de::Error::custom(Error::InvalidFraction));
+                }
+                let time = LocalTime {
+                    naive: HmsTime {
+                        hour: naive.hour,
+                        minute: naive.minute,
+                        second: naive.second,
+                    },
+                    fraction: (crate::round(fraction * 1e9) as u32).min(999_999_999),
+                    fraction_digits: 0,
+                };
+                if time.is_valid() {
+                    Ok(time)
+                } else {
+                    Err(de::Error::custom(Error::InvalidDate))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_time_hms() {
+        let time = LocalTime {
+            naive: HmsTime {
+                hour: 16,
+                minute: 43,
+                second: 52,
+            },
+            fraction: 100_000_000,
+            fraction_digits: 0,
+        };
+
+        assert_eq!(serde_json::to_string(&time).unwrap(), r#""16:43:52.1""#);
+        assert_eq!(
+            serde_json::from_str::<LocalTime>(r#""16:43:52.100000""#).unwrap(),
+            time
+        );
+        assert_eq!(
+            serde_json::from_str::<LocalTime>(
+                r#"{"naive": {"hour": 16, "minute": 43, "second": 52}, "fraction": 0.1}"#
+            )
+            .unwrap(),
+            time
+        );
+        assert!(serde_json::from_str::<LocalTime>(r#""25:43:52""#).is_err());
+
+        let json = r#""16:43:52.123456789""#;
+        let time = serde_json::from_str::<LocalTime>(json).unwrap();
+        assert_eq!(time.fraction, 123_456_789);
+        assert_eq!(serde_json::to_string(&time).unwrap(), json);
+    }
+
+    #[test]
+    fn round_trip() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Event {
+            date: YmdDate,
+            start: GlobalTime<HmTime>,
+            at: DateTime<Date, GlobalTime>,
+            around: PartialDateTime,
+            when: Option<ApproxAnyTime>,
+            zone: Timezone,
+            during: Interval,
+            every: RecurringInterval,
+        }
+
+        let json = r#"{"date":"2018-08-02","start":"12:30+02:00","at":"2018-W31-4T12:30:15.5Z","around":"2018-08","when":"T12","zone":"-05:00","during":"2018-08-02T12:00:00Z/P1DT2H","every":"R3/P1D/2018-08-02T12:00:00Z"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(event.date.day, 2);
+        assert_eq!(event.start.timezone, crate::Timezone::Fixed(120));
+        assert_eq!(event.at.time.local.fraction, 500_000_000);
+        assert_eq!(event.when.unwrap().hour(), 12);
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            json.replace(r#""T12""#, r#""12""#)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(serde_json::from_str::<YmdDate>(r#""2018-02-30""#).is_err());
+        assert!(serde_json::from_str::<YmdDate>(r#""2018-0""#).is_err());
+        assert!(serde_json::from_str::<GlobalTime>(r#""12:30:15""#).is_err());
+        assert!(serde_json::from_str::<DateTime<Date, GlobalTime>>("20180802").is_err());
+
+        assert!(serde_json::from_str::<LocalTime>(
+            r#"{"naive": {"hour": 25, "minute": 43, "second": 52}, "fraction": 0.1}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<LocalTime>(
+            r#"{"naive": {"hour": 16, "minute": 43, "second": 52}, "fraction": 1.5}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Timezone>(r#""+25:00""#).is_err());
+
+        let err = serde_json::from_str::<Date>(r#""2018-13-01""#).unwrap_err();
+        assert!(err.to_string().contains("Invalid date"));
+    }
+}
@@ -1,7 +1,7 @@
-use crate::Valid;
+use crate::{Valid, WriteIso8601};
 
 /// Local time (4.2.2.2)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct HmsTime {
     pub hour: u8,
     pub minute: u8,
@@ -9,14 +9,14 @@ pub struct HmsTime {
 }
 
 /// A specific hour and minute (4.2.2.3a)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct HmTime {
     pub hour: u8,
     pub minute: u8,
 }
 
 /// A specific hour (4.2.2.3b)
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
 pub struct HTime {
     pub hour: u8,
 }
@@ -28,6 +28,30 @@ impl From<HTime> for HmTime {
     }
 }
 
+impl HmTime {
+    /// Builds an hour and minute, checking both components.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(hour: u8, minute: u8) -> Result<Self, crate::Error> {
+        let time = Self { hour, minute };
+        time.is_valid()
+            .then_some(time)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl HTime {
+    /// Builds an hour, checking that it is at most 24.
+    /// Prefer this over filling in the field directly.
+    #[inline]
+    pub fn new(hour: u8) -> Result<Self, crate::Error> {
+        let time = Self { hour };
+        time.is_valid()
+            .then_some(time)
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
 impl From<HmTime> for HmsTime {
     #[inline]
     fn from(HmTime { hour, minute }: HmTime) -> Self {
@@ -39,32 +63,221 @@ impl From<HmTime> for HmsTime {
     }
 }
 
+impl HmsTime {
+    /// Builds a time of day, checking each component.
+    /// Prefer this over filling in the fields directly.
+    #[inline]
+    pub fn new(hour: u8, minute: u8, second: u8) -> Result<Self, crate::Error> {
+        let time = Self {
+            hour,
+            minute,
+            second,
+        };
+        time.is_valid()
+            .then_some(time)
+            .ok_or(crate::Error::InvalidDate)
+    }
+
+    /// Replaces a leap second with the first second of the next minute,
+    /// wrapping around at midnight.
+    /// The flag tells whether a leap second was normalized.
+    pub fn normalize_leap_second(&self) -> (HmsTime, bool) {
+        if self.second != 60 {
+            return (*self, false);
+        }
+
+        let minute = (self.minute + 1) % 60;
+        let hour = if minute == 0 {
+            (self.hour + 1) % 24
+        } else {
+            self.hour
+        };
+        (
+            HmsTime {
+                hour,
+                minute,
+                second: 0,
+            },
+            true,
+        )
+    }
+
+    /// Like [`normalize_leap_second`](Self::normalize_leap_second),
+    /// but advances `date` to the next day when wrapping around at midnight.
+    pub fn normalize_with_date(
+        &self,
+        date: crate::YmdDate,
+    ) -> Result<(crate::YmdDate, HmsTime, bool), crate::Error> {
+        let (time, normalized) = self.normalize_leap_second();
+        let date = if normalized && time.hour < self.hour {
+            crate::YmdDate::from_days_since_epoch(date.days_since_epoch() + 1)
+                .ok_or(crate::Error::InvalidDate)?
+        } else {
+            date
+        };
+        Ok((date, time, normalized))
+    }
+}
+
 /// Local time with decimal fraction (4.2.2.4)
-#[derive(PartialEq, Clone, Debug)]
+///
+/// Comparisons and hashing ignore [`fraction_digits`](Self::fraction_digits),
+/// so `12:30:05.25` and `12:30:05.250` are equal.
+#[derive(Clone, Debug)]
 pub struct LocalTime<N = HmsTime>
 where
     N: NaiveTime,
 {
     pub naive: N,
-    pub fraction: f32,
+    /// Decimal fraction of the smallest naive component in billionths,
+    /// so nanoseconds for an [`HmsTime`]. Parsing drops the digits past the ninth.
+    pub fraction: u32,
+    /// Minimum number of fraction digits to write, padded with trailing zeros,
+    /// so that the digits of a parsed time are written back verbatim.
+    /// At most 9, and 0 for the fewest digits needed.
+    pub fraction_digits: u8,
 }
 
 impl<N: NaiveTime + Copy> Copy for LocalTime<N> {}
 
+impl<N: NaiveTime + PartialEq> PartialEq for LocalTime<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.naive == other.naive && self.fraction == other.fraction
+    }
+}
+
+impl<N: NaiveTime + Eq> Eq for LocalTime<N> {}
+
+impl<N: NaiveTime + PartialOrd> PartialOrd for LocalTime<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.naive.partial_cmp(&other.naive)? {
+            core::cmp::Ordering::Equal => Some(self.fraction.cmp(&other.fraction)),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<N: NaiveTime + Ord> Ord for LocalTime<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.naive
+            .cmp(&other.naive)
+            .then(self.fraction.cmp(&other.fraction))
+    }
+}
+
+impl<N: NaiveTime + core::hash::Hash> core::hash::Hash for LocalTime<N> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.naive.hash(state);
+        self.fraction.hash(state);
+    }
+}
+
 /// Local time with timezone (4.2.4)
-#[derive(PartialEq, Clone, Debug)]
+///
+/// Equality and hashing compare the time as written, so the same instant
+/// in two timezones makes two distinct keys. Use
+/// [`same_instant`](Self::same_instant) to compare instants instead.
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub struct GlobalTime<N = HmsTime>
 where
     N: NaiveTime,
 {
     pub local: LocalTime<N>,
-    /// Difference from UTC in minutes (4.2.5.2)
-    pub timezone: i16,
+    pub timezone: Timezone,
 }
 
 impl<N: NaiveTime + Copy> Copy for GlobalTime<N> {}
 
-#[derive(PartialEq, Clone, Debug)]
+impl GlobalTime<HmsTime> {
+    /// Converts to the same instant written in UTC.
+    ///
+    /// The second element is the number of days the conversion
+    /// crossed, negative when the UTC time falls on the previous day.
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let time: GlobalTime = "01:30:00+02:00".parse().unwrap();
+    /// let (utc, days) = time.to_utc();
+    /// assert_eq!(utc.to_string(), "23:30:00Z");
+    /// assert_eq!(days, -1);
+    /// ```
+    pub fn to_utc(self) -> (Self, i8) {
+        let minutes = self.local.naive.hour as i32 * 60 + self.local.naive.minute as i32
+            - self.timezone.minutes() as i32;
+        let time = Self {
+            local: LocalTime {
+                naive: HmsTime {
+                    hour: (minutes.rem_euclid(1_440) / 60) as u8,
+                    minute: minutes.rem_euclid(60) as u8,
+                    ..self.local.naive
+                },
+                ..self.local
+            },
+            timezone: Timezone::Utc,
+        };
+        (time, minutes.div_euclid(1_440) as i8)
+    }
+}
+
+/// Difference from UTC (4.2.5.2)
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug, Default)]
+pub enum Timezone {
+    /// UTC written with the `Z` designator (4.2.4)
+    #[default]
+    Utc,
+    /// Offset in minutes written as `±hh:mm`, which may be `+00:00`
+    Fixed(i16),
+    /// `-00:00`, a time in UTC whose local offset is unknown (RFC 3339, 4.3)
+    Unknown,
+}
+
+impl Timezone {
+    /// Difference from UTC in minutes, which is zero for
+    /// [`Utc`](Self::Utc) and [`Unknown`](Self::Unknown)
+    #[inline]
+    pub fn minutes(self) -> i16 {
+        match self {
+            Self::Utc | Self::Unknown => 0,
+            Self::Fixed(minutes) => minutes,
+        }
+    }
+}
+
+/// Orders by offset, with `Z` then `-00:00` just before `+00:00`
+impl Ord for Timezone {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let rank = |t: &Self| match t {
+            Self::Utc => 0,
+            Self::Unknown => 1,
+            Self::Fixed(_) => 2,
+        };
+        self.minutes()
+            .cmp(&other.minutes())
+            .then_with(|| rank(self).cmp(&rank(other)))
+    }
+}
+
+impl PartialOrd for Timezone {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<i16> for Timezone {
+    #[inline]
+    fn from(minutes: i16) -> Self {
+        Self::Fixed(minutes)
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub enum AnyTime<N = HmsTime>
 where
     N: NaiveTime,
@@ -75,112 +288,882 @@ where
 
 impl<N: NaiveTime + Copy> Copy for AnyTime<N> {}
 
+/// Global time whose timezone is a multiple of 15 minutes
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub struct StrictTimezone(pub(crate) GlobalTime<HmsTime>);
+
+impl StrictTimezone {
+    #[inline]
+    pub fn new(t: GlobalTime<HmsTime>) -> Result<Self, crate::Error> {
+        (t.timezone.minutes() % 15 == 0)
+            .then_some(Self(t))
+            .ok_or(crate::Error::InvalidDate)
+    }
+}
+
+impl AsRef<GlobalTime<HmsTime>> for StrictTimezone {
+    #[inline]
+    fn as_ref(&self) -> &GlobalTime<HmsTime> {
+        &self.0
+    }
+}
+
+impl From<StrictTimezone> for GlobalTime<HmsTime> {
+    #[inline]
+    fn from(t: StrictTimezone) -> Self {
+        t.0
+    }
+}
+
 pub trait NaiveTime {}
 
 impl NaiveTime for HmsTime {}
 impl NaiveTime for HmTime {}
 impl NaiveTime for HTime {}
 
+/// Billionths of a fraction of `unit` nanoseconds as nanoseconds,
+/// staying below one `unit` so that the naive components never carry over.
+#[inline]
+fn fraction_nanos(fraction: u32, unit: u64) -> u64 {
+    (fraction as u64 * (unit / 1_000_000_000)).min(unit - 1)
+}
+
 impl LocalTime<HmsTime> {
     #[inline]
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 1_000_000_000.) as u32
+        fraction_nanos(self.fraction, 1_000_000_000) as u32
     }
 }
 
 impl LocalTime<HmTime> {
     #[inline]
     pub fn second(&self) -> u8 {
-        (self.fraction * 60.) as u8
+        (fraction_nanos(self.fraction, 60_000_000_000) / 1_000_000_000) as u8
     }
 
     #[inline]
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 60_000_000_000.) as u32 % 1_000_000_000
+        (fraction_nanos(self.fraction, 60_000_000_000) % 1_000_000_000) as u32
     }
 }
 
 impl LocalTime<HTime> {
     #[inline]
     pub fn minute(&self) -> u8 {
-        (self.fraction * 60.) as u8
+        (fraction_nanos(self.fraction, 3_600_000_000_000) / 60_000_000_000) as u8
     }
 
     #[inline]
     pub fn second(&self) -> u8 {
-        (self.fraction * 3_600.) as u8 % 60
+        (fraction_nanos(self.fraction, 3_600_000_000_000) / 1_000_000_000 % 60) as u8
     }
 
     #[inline]
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 3_600_000_000_000.) as u32 % 1_000_000_000
+        (fraction_nanos(self.fraction, 3_600_000_000_000) % 1_000_000_000) as u32
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
 pub enum ApproxNaiveTime {
     HMS(HmsTime),
     HM(HmTime),
     H(HTime),
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ApproxLocalTime {
-    HMS(LocalTime<HmsTime>),
-    HM(LocalTime<HmTime>),
-    H(LocalTime<HTime>),
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum ApproxLocalTime {
+    HMS(LocalTime<HmsTime>),
+    HM(LocalTime<HmTime>),
+    H(LocalTime<HTime>),
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum ApproxGlobalTime {
+    HMS(GlobalTime<HmsTime>),
+    HM(GlobalTime<HmTime>),
+    H(GlobalTime<HTime>),
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum ApproxAnyTime {
+    HMS(AnyTime<HmsTime>),
+    HM(AnyTime<HmTime>),
+    H(AnyTime<HTime>),
+}
+
+impl ApproxAnyTime {
+    /// Returns the local time if this is a local time of full accuracy.
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use iso_8601::*;
+    ///
+    /// let time = ApproxAnyTime::from_str("13:42:05").unwrap();
+    /// assert_eq!(
+    ///     time.to_local_hms(),
+    ///     Some(&LocalTime {
+    ///         naive: HmsTime {
+    ///             hour: 13,
+    ///             minute: 42,
+    ///             second: 5,
+    ///         },
+    ///         fraction: 0,
+    ///         fraction_digits: 0,
+    ///     })
+    /// );
+    /// assert_eq!(time.to_global_hms(), None);
+    /// ```
+    #[inline]
+    pub fn to_local_hms(&self) -> Option<&LocalTime<HmsTime>> {
+        match self {
+            Self::HMS(AnyTime::Local(time)) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Returns the global time if this is a global time of full accuracy.
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use iso_8601::*;
+    ///
+    /// let time = ApproxAnyTime::from_str("13:42:05+01:00").unwrap();
+    /// assert_eq!(time.to_global_hms().map(|t| t.timezone), Some(Timezone::Fixed(60)));
+    /// assert_eq!(time.to_local_hms(), None);
+    /// ```
+    #[inline]
+    pub fn to_global_hms(&self) -> Option<&GlobalTime<HmsTime>> {
+        match self {
+            Self::HMS(AnyTime::Global(time)) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Returns the local time if this is a local time with minute accuracy.
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use iso_8601::*;
+    ///
+    /// let time = ApproxAnyTime::from_str("13:42").unwrap();
+    /// assert_eq!(time.to_local_hm().map(|t| t.naive.minute), Some(42));
+    /// ```
+    #[inline]
+    pub fn to_local_hm(&self) -> Option<&LocalTime<HmTime>> {
+        match self {
+            Self::HM(AnyTime::Local(time)) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Returns the global time if this is a global time with minute accuracy.
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use iso_8601::*;
+    ///
+    /// let time = ApproxAnyTime::from_str("13:42Z").unwrap();
+    /// assert_eq!(time.to_global_hm().map(|t| t.timezone), Some(Timezone::Utc));
+    /// ```
+    #[inline]
+    pub fn to_global_hm(&self) -> Option<&GlobalTime<HmTime>> {
+        match self {
+            Self::HM(AnyTime::Global(time)) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// First instant of the period designated by the time,
+    /// with omitted minutes and seconds set to 0, so `13` resolves to `13:00:00`.
+    /// A decimal fraction of the smallest component is carried into the
+    /// omitted ones, so `13:42.5` resolves to `13:42:30`.
+    #[inline]
+    pub fn resolve_start(&self) -> AnyTime<HmsTime> {
+        (*self).into()
+    }
+
+    /// Last whole second of the period designated by the time, with
+    /// omitted minutes and seconds set to 59, so `13` resolves to `13:59:59`.
+    /// Times with a decimal fraction, times of full accuracy and hour 24
+    /// designate a single instant, and resolve like
+    /// [`resolve_start`](Self::resolve_start).
+    pub fn resolve_end(&self) -> AnyTime<HmsTime> {
+        let end = |hour, minute, fraction| {
+            (fraction == 0 && hour < 24).then_some(LocalTime {
+                naive: HmsTime {
+                    hour,
+                    minute,
+                    second: 59,
+                },
+                fraction: 0,
+                fraction_digits: 0,
+            })
+        };
+        let local = match self {
+            Self::HMS(_) => None,
+            Self::HM(AnyTime::Global(GlobalTime { local, .. }) | AnyTime::Local(local)) => {
+                end(local.naive.hour, local.naive.minute, local.fraction)
+            }
+            Self::H(AnyTime::Global(GlobalTime { local, .. }) | AnyTime::Local(local)) => {
+                end(local.naive.hour, 59, local.fraction)
+            }
+        };
+        match (local, self.resolve_start()) {
+            (Some(local), AnyTime::Global(time)) => AnyTime::Global(GlobalTime {
+                local,
+                timezone: time.timezone,
+            }),
+            (Some(local), AnyTime::Local(_)) => AnyTime::Local(local),
+            (None, time) => time,
+        }
+    }
+}
+
+/// Access to the time of day, with components
+/// missing from reduced accuracy representations set to zero.
+/// Times with a timezone return their local wall-clock values.
+pub trait Timelike {
+    fn hour(&self) -> u8;
+    fn minute(&self) -> u8;
+    fn second(&self) -> u8;
+    fn nanosecond(&self) -> u32;
+
+    /// Difference from UTC in minutes, or `None` for local times.
+    /// `-00:00` is a time in UTC, so its offset is zero.
+    #[inline]
+    fn offset_minutes(&self) -> Option<i16> {
+        None
+    }
+
+    /// Like [`is_valid`](Valid::is_valid), but also rejects
+    /// the leap seconds and hour 24 that `policy` forbids.
+    #[inline]
+    fn validate_with(&self, policy: ValidationPolicy) -> bool
+    where
+        Self: Valid,
+    {
+        self.is_valid()
+            && (policy.allow_leap_second || self.second() != 60)
+            && (policy.allow_hour_24 || self.hour() != 24)
+    }
+}
+
+/// Which of the unusual but well formed times are accepted
+/// by [`Timelike::validate_with`].
+///
+/// The default accepts them all, like [`Valid::is_valid`].
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub struct ValidationPolicy {
+    /// Second 60, on any day since leap seconds are not predictable
+    pub allow_leap_second: bool,
+    /// `24:00:00`, the midnight at the end of the day (4.2.3)
+    pub allow_hour_24: bool,
+}
+
+impl ValidationPolicy {
+    /// RFC 3339 has neither hour 24 nor, outside of its grammar, leap seconds
+    pub const RFC_3339: Self = Self {
+        allow_leap_second: false,
+        allow_hour_24: false,
+    };
+}
+
+impl Default for ValidationPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            allow_leap_second: true,
+            allow_hour_24: true,
+        }
+    }
+}
+
+impl Timelike for HmsTime {
+    #[inline]
+    fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        self.second
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        0
+    }
+}
+
+impl Timelike for HmTime {
+    #[inline]
+    fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        0
+    }
+}
+
+impl Timelike for HTime {
+    #[inline]
+    fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        0
+    }
+}
+
+impl<N> Timelike for LocalTime<N>
+where
+    N: NaiveTime + Copy,
+    LocalTime<HmsTime>: From<LocalTime<N>>,
+{
+    #[inline]
+    fn hour(&self) -> u8 {
+        LocalTime::<HmsTime>::from(*self).naive.hour
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        LocalTime::<HmsTime>::from(*self).naive.minute
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        LocalTime::<HmsTime>::from(*self).naive.second
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        LocalTime::<HmsTime>::from(*self).nanosecond()
+    }
+}
+
+impl<N> Timelike for GlobalTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: Timelike,
+{
+    #[inline]
+    fn hour(&self) -> u8 {
+        self.local.hour()
+    }
+
+    #[inline]
+    fn minute(&self) -> u8 {
+        self.local.minute()
+    }
+
+    #[inline]
+    fn second(&self) -> u8 {
+        self.local.second()
+    }
+
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.local.nanosecond()
+    }
+
+    #[inline]
+    fn offset_minutes(&self) -> Option<i16> {
+        Some(self.timezone.minutes())
+    }
+}
+
+macro_rules! impl_timelike_delegate {
+    ($ty:ty, $($variant:path),+) => {
+        impl Timelike for $ty {
+            #[inline]
+            fn hour(&self) -> u8 {
+                match self {
+                    $($variant(time) => time.hour(),)+
+                }
+            }
+
+            #[inline]
+            fn minute(&self) -> u8 {
+                match self {
+                    $($variant(time) => time.minute(),)+
+                }
+            }
+
+            #[inline]
+            fn second(&self) -> u8 {
+                match self {
+                    $($variant(time) => time.second(),)+
+                }
+            }
+
+            #[inline]
+            fn nanosecond(&self) -> u32 {
+                match self {
+                    $($variant(time) => time.nanosecond(),)+
+                }
+            }
+
+            #[inline]
+            fn offset_minutes(&self) -> Option<i16> {
+                match self {
+                    $($variant(time) => time.offset_minutes(),)+
+                }
+            }
+        }
+    };
+}
+
+impl_timelike_delegate!(AnyTime<HmsTime>, AnyTime::Global, AnyTime::Local);
+impl_timelike_delegate!(AnyTime<HmTime>, AnyTime::Global, AnyTime::Local);
+impl_timelike_delegate!(AnyTime<HTime>, AnyTime::Global, AnyTime::Local);
+impl_timelike_delegate!(
+    ApproxLocalTime,
+    ApproxLocalTime::HMS,
+    ApproxLocalTime::HM,
+    ApproxLocalTime::H
+);
+impl_timelike_delegate!(
+    ApproxGlobalTime,
+    ApproxGlobalTime::HMS,
+    ApproxGlobalTime::HM,
+    ApproxGlobalTime::H
+);
+impl_timelike_delegate!(
+    ApproxAnyTime,
+    ApproxAnyTime::HMS,
+    ApproxAnyTime::HM,
+    ApproxAnyTime::H
+);
+
+/// Times that can be midnight at the end of the day, `24:00:00`
+pub trait EndOfDay: Sized {
+    /// The same time at hour 0, if the hour is 24
+    fn start_of_next_day(&self) -> Option<Self>;
+}
+
+macro_rules! impl_end_of_day_naive {
+    ($ty:ty) => {
+        impl EndOfDay for $ty {
+            #[inline]
+            fn start_of_next_day(&self) -> Option<Self> {
+                (self.hour == 24).then(|| {
+                    let mut time = *self;
+                    time.hour = 0;
+                    time
+                })
+            }
+        }
+    };
+}
+
+impl_end_of_day_naive!(HmsTime);
+impl_end_of_day_naive!(HmTime);
+impl_end_of_day_naive!(HTime);
+
+impl<N> EndOfDay for LocalTime<N>
+where
+    N: NaiveTime + EndOfDay,
+{
+    #[inline]
+    fn start_of_next_day(&self) -> Option<Self> {
+        Some(Self {
+            naive: self.naive.start_of_next_day()?,
+            ..*self
+        })
+    }
+}
+
+impl<N> EndOfDay for GlobalTime<N>
+where
+    N: NaiveTime + EndOfDay,
+{
+    #[inline]
+    fn start_of_next_day(&self) -> Option<Self> {
+        Some(Self {
+            local: self.local.start_of_next_day()?,
+            timezone: self.timezone,
+        })
+    }
+}
+
+macro_rules! impl_end_of_day_delegate {
+    ($ty:ty, $($variant:path),+) => {
+        impl EndOfDay for $ty {
+            #[inline]
+            fn start_of_next_day(&self) -> Option<Self> {
+                match self {
+                    $($variant(time) => time.start_of_next_day().map($variant),)+
+                }
+            }
+        }
+    };
+}
+
+impl_end_of_day_delegate!(AnyTime<HmsTime>, AnyTime::Global, AnyTime::Local);
+impl_end_of_day_delegate!(AnyTime<HmTime>, AnyTime::Global, AnyTime::Local);
+impl_end_of_day_delegate!(AnyTime<HTime>, AnyTime::Global, AnyTime::Local);
+impl_end_of_day_delegate!(
+    ApproxLocalTime,
+    ApproxLocalTime::HMS,
+    ApproxLocalTime::HM,
+    ApproxLocalTime::H
+);
+impl_end_of_day_delegate!(
+    ApproxGlobalTime,
+    ApproxGlobalTime::HMS,
+    ApproxGlobalTime::HM,
+    ApproxGlobalTime::H
+);
+impl_end_of_day_delegate!(
+    ApproxAnyTime,
+    ApproxAnyTime::HMS,
+    ApproxAnyTime::HM,
+    ApproxAnyTime::H
+);
+
+impl_fromstr_parse!(GlobalTime<HmsTime>, time_global_hms);
+impl_fromstr_parse!(GlobalTime<HmTime>, time_global_hm);
+impl_fromstr_parse!(GlobalTime<HTime>, time_global_h);
+impl_fromstr_parse!(LocalTime<HmsTime>, time_local_hms);
+impl_fromstr_parse!(LocalTime<HmTime>, time_local_hm);
+impl_fromstr_parse!(LocalTime<HTime>, time_local_h);
+impl_fromstr_parse!(AnyTime<HmsTime>, time_any_hms);
+impl_fromstr_parse!(AnyTime<HmTime>, time_any_hm);
+impl_fromstr_parse!(AnyTime<HTime>, time_any_h);
+impl_fromstr_parse!(ApproxGlobalTime, time_global_approx);
+impl_fromstr_parse!(ApproxLocalTime, time_local_approx);
+impl_fromstr_parse!(ApproxAnyTime, time_any_approx);
+impl_fromstr_parse!(StrictTimezone, time_global_hms_strict_tz);
+impl_fromstr_parse!(Timezone, timezone);
+
+impl_parse_strict!(AnyTime<HmsTime>, time_strict);
+
+impl WriteIso8601 for HmsTime {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        if extended {
+            write!(w, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        } else {
+            write!(w, "{:02}{:02}{:02}", self.hour, self.minute, self.second)
+        }
+    }
+}
+
+impl WriteIso8601 for HmTime {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        if extended {
+            write!(w, "{:02}:{:02}", self.hour, self.minute)
+        } else {
+            write!(w, "{:02}{:02}", self.hour, self.minute)
+        }
+    }
+}
+
+impl WriteIso8601 for HTime {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, _: bool) -> core::fmt::Result {
+        write!(w, "{:02}", self.hour)
+    }
+}
+
+impl WriteIso8601 for ApproxNaiveTime {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match self {
+            Self::HMS(time) => time.write_iso8601(w, extended),
+            Self::HM(time) => time.write_iso8601(w, extended),
+            Self::H(time) => time.write_iso8601(w, extended),
+        }
+    }
+}
+
+impl_display_iso8601!(HmsTime);
+impl_display_iso8601!(HmTime);
+impl_display_iso8601!(HTime);
+impl_display_iso8601!(ApproxNaiveTime);
+
+/// Omits the decimal fraction when it is zero, otherwise writes it
+/// without trailing zeros past [`fraction_digits`](LocalTime::fraction_digits).
+impl<N> WriteIso8601 for LocalTime<N>
+where
+    N: NaiveTime + WriteIso8601,
+{
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        self.naive.write_iso8601(w, extended)?;
+        write_fraction(w, self.fraction, self.fraction_digits)
+    }
+}
+
+impl<N> core::fmt::Display for LocalTime<N>
+where
+    N: NaiveTime + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.naive.fmt(f)?;
+        write_fraction(f, self.fraction, self.fraction_digits)
+    }
+}
+
+#[inline]
+// `u32::is_multiple_of` would require Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn write_fraction<W: core::fmt::Write>(
+    w: &mut W,
+    fraction: u32,
+    min_digits: u8,
+) -> core::fmt::Result {
+    if fraction != 0 || min_digits != 0 {
+        let min_digits = min_digits.min(9) as usize;
+        let mut digits = 9;
+        let mut fraction = fraction;
+        while digits > min_digits && fraction % 10 == 0 {
+            fraction /= 10;
+            digits -= 1;
+        }
+        write!(w, ".{:0digits$}", fraction, digits = digits)?;
+    }
+    Ok(())
+}
+
+/// Writes `Z` for [`Utc`](Timezone::Utc), otherwise the `+hh:mm` offset,
+/// or `+hhmm` in the basic format, with `-00:00` for [`Unknown`](Timezone::Unknown)
+impl WriteIso8601 for Timezone {
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match *self {
+            Self::Utc => w.write_char('Z'),
+            Self::Unknown if extended => w.write_str("-00:00"),
+            Self::Unknown => w.write_str("-0000"),
+            Self::Fixed(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                let offset = minutes.unsigned_abs();
+                let separator = if extended { ":" } else { "" };
+                write!(
+                    w,
+                    "{}{:02}{}{:02}",
+                    sign,
+                    offset / 60,
+                    separator,
+                    offset % 60
+                )
+            }
+        }
+    }
+}
+
+impl_display_iso8601!(Timezone);
+
+impl<N> WriteIso8601 for GlobalTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: WriteIso8601,
+{
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        self.local.write_iso8601(w, extended)?;
+        self.timezone.write_iso8601(w, extended)
+    }
+}
+
+impl<N> core::fmt::Display for GlobalTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.local.fmt(f)?;
+        self.timezone.fmt(f)
+    }
+}
+
+impl<N> GlobalTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: Timelike,
+{
+    /// Seconds from midnight UTC, which may fall on the previous
+    /// or next day, and the nanoseconds within the second
+    #[inline]
+    fn utc(&self) -> (i32, u32) {
+        let seconds = self.hour() as i32 * 3_600 + self.minute() as i32 * 60 + self.second() as i32;
+        (
+            seconds - self.timezone.minutes() as i32 * 60,
+            self.nanosecond(),
+        )
+    }
+
+    /// Whether both times denote the same instant, whatever their
+    /// timezones and accuracies. Both times are taken to be on the same day.
+    ///
+    /// ```
+    /// use iso_8601::*;
+    ///
+    /// let paris: GlobalTime = "14:30:00+02:00".parse().unwrap();
+    /// let utc: GlobalTime<HmTime> = "12:30Z".parse().unwrap();
+    /// assert!(paris.same_instant(&utc));
+    /// assert_ne!(paris, "12:30:00Z".parse().unwrap());
+    /// ```
+    #[inline]
+    pub fn same_instant<M>(&self, other: &GlobalTime<M>) -> bool
+    where
+        M: NaiveTime,
+        LocalTime<M>: Timelike,
+    {
+        self.utc() == other.utc()
+    }
+}
+
+/// Orders by the time of day in UTC, then by timezone
+/// so that only identical times compare equal.
+/// Both times are taken to be on the same day.
+impl<N> PartialOrd for GlobalTime<N>
+where
+    N: NaiveTime + PartialOrd,
+    LocalTime<N>: Timelike,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self
+            .utc()
+            .cmp(&other.utc())
+            .then_with(|| self.timezone.cmp(&other.timezone))
+        {
+            core::cmp::Ordering::Equal => self.local.partial_cmp(&other.local),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Same order as [`PartialOrd`]
+impl<N> Ord for GlobalTime<N>
+where
+    N: NaiveTime + Ord,
+    LocalTime<N>: Timelike,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.utc()
+            .cmp(&other.utc())
+            .then_with(|| self.timezone.cmp(&other.timezone))
+            .then_with(|| self.local.cmp(&other.local))
+    }
+}
+
+/// Local and global times are not on the same timeline,
+/// so comparing one to the other returns `None`.
+impl<N> PartialOrd for AnyTime<N>
+where
+    N: NaiveTime + PartialOrd,
+    LocalTime<N>: Timelike,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self, other) {
+            (Self::Global(a), Self::Global(b)) => a.partial_cmp(b),
+            (Self::Local(a), Self::Local(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl<N> WriteIso8601 for AnyTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: WriteIso8601,
+{
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        match self {
+            Self::Global(time) => time.write_iso8601(w, extended),
+            Self::Local(time) => time.write_iso8601(w, extended),
+        }
+    }
+}
+
+impl<N> core::fmt::Display for AnyTime<N>
+where
+    N: NaiveTime,
+    LocalTime<N>: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Global(time) => time.fmt(f),
+            Self::Local(time) => time.fmt(f),
+        }
+    }
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ApproxGlobalTime {
-    HMS(GlobalTime<HmsTime>),
-    HM(GlobalTime<HmTime>),
-    H(GlobalTime<HTime>),
-}
+macro_rules! impl_write_iso8601_approx {
+    ($ty:ty) => {
+        impl WriteIso8601 for $ty {
+            fn write_iso8601<W: core::fmt::Write>(
+                &self,
+                w: &mut W,
+                extended: bool,
+            ) -> core::fmt::Result {
+                match self {
+                    Self::HMS(time) => time.write_iso8601(w, extended),
+                    Self::HM(time) => time.write_iso8601(w, extended),
+                    Self::H(time) => time.write_iso8601(w, extended),
+                }
+            }
+        }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ApproxAnyTime {
-    HMS(AnyTime<HmsTime>),
-    HM(AnyTime<HmTime>),
-    H(AnyTime<HTime>),
+        impl_display_iso8601!($ty);
+    };
 }
 
-pub trait Timelike {}
+impl_write_iso8601_approx!(ApproxLocalTime);
+impl_write_iso8601_approx!(ApproxGlobalTime);
+impl_write_iso8601_approx!(ApproxAnyTime);
 
-impl<N: NaiveTime> Timelike for N {}
-impl<N: NaiveTime> Timelike for LocalTime<N> {}
-impl<N: NaiveTime> Timelike for GlobalTime<N> {}
-impl<N: NaiveTime> Timelike for AnyTime<N> {}
-impl Timelike for ApproxLocalTime {}
-impl Timelike for ApproxGlobalTime {}
-impl Timelike for ApproxAnyTime {}
+impl WriteIso8601 for StrictTimezone {
+    #[inline]
+    fn write_iso8601<W: core::fmt::Write>(&self, w: &mut W, extended: bool) -> core::fmt::Result {
+        self.0.write_iso8601(w, extended)
+    }
+}
 
-impl_fromstr_parse!(GlobalTime<HmsTime>, time_global_hms);
-impl_fromstr_parse!(GlobalTime<HmTime>, time_global_hm);
-impl_fromstr_parse!(GlobalTime<HTime>, time_global_h);
-impl_fromstr_parse!(LocalTime<HmsTime>, time_local_hms);
-impl_fromstr_parse!(LocalTime<HmTime>, time_local_hm);
-impl_fromstr_parse!(LocalTime<HTime>, time_local_h);
-impl_fromstr_parse!(AnyTime<HmsTime>, time_any_hms);
-impl_fromstr_parse!(AnyTime<HmTime>, time_any_hm);
-impl_fromstr_parse!(AnyTime<HTime>, time_any_h);
-impl_fromstr_parse!(ApproxGlobalTime, time_global_approx);
-impl_fromstr_parse!(ApproxLocalTime, time_local_approx);
-impl_fromstr_parse!(ApproxAnyTime, time_any_approx);
+impl_display_iso8601!(StrictTimezone);
 
 impl Valid for HmsTime {
     /// Accepts leap seconds on any day
-    /// since they are not predictable.
+    /// since they are not predictable, but not at `24:00:60`.
     #[inline]
     fn is_valid(&self) -> bool {
-        HmTime::from(*self).is_valid() && self.second <= 60
+        HmTime::from(*self).is_valid() && self.second <= 60 && (self.hour < 24 || self.second == 0)
     }
 }
 
 impl Valid for HmTime {
+    /// Hour 24 is midnight at the end of the day (4.2.3),
+    /// so the lesser components must all be zero.
     #[inline]
     fn is_valid(&self) -> bool {
-        HTime::from(*self).is_valid() && self.minute <= 59
+        HTime::from(*self).is_valid() && self.minute <= 59 && (self.hour < 24 || self.minute == 0)
     }
 }
 
@@ -193,27 +1176,39 @@ impl Valid for HTime {
 
 impl<N> Valid for LocalTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Timelike + Valid,
 {
     #[inline]
     fn is_valid(&self) -> bool {
-        self.naive.is_valid() && self.fraction >= 0. && self.fraction < 1.
+        self.naive.is_valid()
+            && self.fraction < 1_000_000_000
+            && (self.naive.hour() < 24 || self.fraction == 0)
     }
 }
 
 impl<N> Valid for GlobalTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Timelike + Valid,
 {
     #[inline]
     fn is_valid(&self) -> bool {
-        self.local.is_valid() && self.timezone > -24 * 60 && self.timezone < 24 * 60
+        self.local.is_valid() && self.timezone.is_valid()
+    }
+}
+
+impl Valid for Timezone {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        match *self {
+            Self::Utc | Self::Unknown => true,
+            Self::Fixed(minutes) => minutes > -24 * 60 && minutes < 24 * 60,
+        }
     }
 }
 
 impl<N> Valid for AnyTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Timelike + Valid,
 {
     #[inline]
     fn is_valid(&self) -> bool {
@@ -224,6 +1219,13 @@ where
     }
 }
 
+impl Valid for StrictTimezone {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.0.is_valid() && self.0.timezone.minutes() % 15 == 0
+    }
+}
+
 impl Valid for ApproxLocalTime {
     #[inline]
     fn is_valid(&self) -> bool {
@@ -292,6 +1294,13 @@ impl From<HTime> for HmsTime {
     }
 }
 
+/// Billionths of a component `factor` times larger, rounded to the nearest
+/// and kept below one so that the naive components never carry over.
+#[inline]
+fn rescale(billionths: u64, factor: u64) -> u32 {
+    ((billionths + factor / 2) / factor).min(999_999_999) as u32
+}
+
 impl From<LocalTime<HmsTime>> for LocalTime<HmTime> {
     #[inline]
     fn from(t: LocalTime<HmsTime>) -> Self {
@@ -300,7 +1309,11 @@ impl From<LocalTime<HmsTime>> for LocalTime<HmTime> {
                 hour: t.naive.hour,
                 minute: t.naive.minute,
             },
-            fraction: (t.naive.second as f32 + t.fraction) / 60.,
+            fraction: rescale(
+                t.naive.second as u64 * 1_000_000_000 + t.fraction as u64,
+                60,
+            ),
+            fraction_digits: 0,
         }
     }
 }
@@ -310,7 +1323,13 @@ impl From<LocalTime<HmsTime>> for LocalTime<HTime> {
     fn from(t: LocalTime<HmsTime>) -> Self {
         Self {
             naive: HTime { hour: t.naive.hour },
-            fraction: t.naive.minute as f32 / 60. + (t.naive.second as f32 + t.fraction) / 3_600.,
+            fraction: rescale(
+                t.naive.minute as u64 * 60_000_000_000
+                    + t.naive.second as u64 * 1_000_000_000
+                    + t.fraction as u64,
+                3_600,
+            ),
+            fraction_digits: 0,
         }
     }
 }
@@ -320,7 +1339,26 @@ impl From<LocalTime<HmTime>> for LocalTime<HTime> {
     fn from(t: LocalTime<HmTime>) -> Self {
         Self {
             naive: HTime { hour: t.naive.hour },
-            fraction: (t.naive.minute as f32 + t.fraction) / 60.,
+            fraction: rescale(
+                t.naive.minute as u64 * 1_000_000_000 + t.fraction as u64,
+                60,
+            ),
+            fraction_digits: 0,
+        }
+    }
+}
+
+impl From<LocalTime<HTime>> for LocalTime<HmTime> {
+    #[inline]
+    fn from(t: LocalTime<HTime>) -> Self {
+        let nanos = fraction_nanos(t.fraction, 3_600_000_000_000) % 60_000_000_000;
+        Self {
+            naive: HmTime {
+                hour: t.naive.hour,
+                minute: t.minute(),
+            },
+            fraction: (nanos / 60) as u32,
+            fraction_digits: 0,
         }
     }
 }
@@ -334,7 +1372,8 @@ impl From<LocalTime<HmTime>> for LocalTime<HmsTime> {
                 minute: t.naive.minute,
                 second: t.second(),
             },
-            fraction: (t.fraction * 60.) % 1.,
+            fraction: t.nanosecond(),
+            fraction_digits: 0,
         }
     }
 }
@@ -348,7 +1387,8 @@ impl From<LocalTime<HTime>> for LocalTime<HmsTime> {
                 minute: t.minute(),
                 second: t.second(),
             },
-            fraction: (t.fraction * 3600.) % 1.,
+            fraction: t.nanosecond(),
+            fraction_digits: 0,
         }
     }
 }
@@ -383,6 +1423,16 @@ impl From<GlobalTime<HmTime>> for GlobalTime<HTime> {
     }
 }
 
+impl From<GlobalTime<HTime>> for GlobalTime<HmTime> {
+    #[inline]
+    fn from(t: GlobalTime<HTime>) -> Self {
+        Self {
+            local: t.local.into(),
+            timezone: t.timezone,
+        }
+    }
+}
+
 impl From<GlobalTime<HmTime>> for GlobalTime<HmsTime> {
     #[inline]
     fn from(t: GlobalTime<HmTime>) -> Self {
@@ -403,6 +1453,42 @@ impl From<GlobalTime<HTime>> for GlobalTime<HmsTime> {
     }
 }
 
+/// Drops the decimal fraction.
+impl From<GlobalTime<HmsTime>> for (HmsTime, Timezone) {
+    #[inline]
+    fn from(t: GlobalTime<HmsTime>) -> Self {
+        (t.local.naive, t.timezone)
+    }
+}
+
+impl From<(HmsTime, Timezone)> for GlobalTime<HmsTime> {
+    #[inline]
+    fn from((naive, timezone): (HmsTime, Timezone)) -> Self {
+        Self {
+            local: LocalTime {
+                naive,
+                fraction: 0,
+                fraction_digits: 0,
+            },
+            timezone,
+        }
+    }
+}
+
+impl From<GlobalTime<HmsTime>> for (LocalTime<HmsTime>, Timezone) {
+    #[inline]
+    fn from(t: GlobalTime<HmsTime>) -> Self {
+        (t.local, t.timezone)
+    }
+}
+
+impl From<(LocalTime<HmsTime>, Timezone)> for GlobalTime<HmsTime> {
+    #[inline]
+    fn from((local, timezone): (LocalTime<HmsTime>, Timezone)) -> Self {
+        Self { local, timezone }
+    }
+}
+
 impl From<AnyTime<HmsTime>> for AnyTime<HmTime> {
     #[inline]
     fn from(t: AnyTime<HmsTime>) -> Self {
@@ -433,6 +1519,36 @@ impl From<AnyTime<HmTime>> for AnyTime<HTime> {
     }
 }
 
+impl From<AnyTime<HTime>> for AnyTime<HmTime> {
+    #[inline]
+    fn from(t: AnyTime<HTime>) -> Self {
+        match t {
+            AnyTime::Global(t) => AnyTime::Global(t.into()),
+            AnyTime::Local(t) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
+impl From<AnyTime<HmTime>> for AnyTime<HmsTime> {
+    #[inline]
+    fn from(t: AnyTime<HmTime>) -> Self {
+        match t {
+            AnyTime::Global(t) => AnyTime::Global(t.into()),
+            AnyTime::Local(t) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
+impl From<AnyTime<HTime>> for AnyTime<HmsTime> {
+    #[inline]
+    fn from(t: AnyTime<HTime>) -> Self {
+        match t {
+            AnyTime::Global(t) => AnyTime::Global(t.into()),
+            AnyTime::Local(t) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
 impl From<ApproxNaiveTime> for HmsTime {
     #[inline]
     fn from(t: ApproxNaiveTime) -> Self {
@@ -466,10 +1582,429 @@ impl From<ApproxGlobalTime> for GlobalTime<HmsTime> {
     }
 }
 
+impl From<ApproxAnyTime> for AnyTime<HmsTime> {
+    #[inline]
+    fn from(t: ApproxAnyTime) -> Self {
+        match t {
+            ApproxAnyTime::HMS(t) => t,
+            ApproxAnyTime::HM(AnyTime::Global(t)) => AnyTime::Global(t.into()),
+            ApproxAnyTime::HM(AnyTime::Local(t)) => AnyTime::Local(t.into()),
+            ApproxAnyTime::H(AnyTime::Global(t)) => AnyTime::Global(t.into()),
+            ApproxAnyTime::H(AnyTime::Local(t)) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
+/// Conversions to a given precision, folding the lesser components
+/// into the decimal fraction or unfolding them out of it.
+macro_rules! impl_precision {
+    ($ty:ident) => {
+        impl<N: NaiveTime> $ty<N> {
+            #[inline]
+            pub fn into_hms(self) -> $ty<HmsTime>
+            where
+                $ty<HmsTime>: From<Self>,
+            {
+                self.into()
+            }
+
+            #[inline]
+            pub fn into_hm(self) -> $ty<HmTime>
+            where
+                $ty<HmTime>: From<Self>,
+            {
+                self.into()
+            }
+
+            #[inline]
+            pub fn into_h(self) -> $ty<HTime>
+            where
+                $ty<HTime>: From<Self>,
+            {
+                self.into()
+            }
+        }
+    };
+    ($ty:ty, $target:ident) => {
+        impl $ty {
+            #[inline]
+            pub fn into_hms(self) -> $target<HmsTime> {
+                match self {
+                    Self::HMS(t) => t,
+                    Self::HM(t) => t.into(),
+                    Self::H(t) => t.into(),
+                }
+            }
+
+            #[inline]
+            pub fn into_hm(self) -> $target<HmTime> {
+                match self {
+                    Self::HMS(t) => t.into(),
+                    Self::HM(t) => t,
+                    Self::H(t) => t.into(),
+                }
+            }
+
+            #[inline]
+            pub fn into_h(self) -> $target<HTime> {
+                match self {
+                    Self::HMS(t) => t.into(),
+                    Self::HM(t) => t.into(),
+                    Self::H(t) => t,
+                }
+            }
+        }
+    };
+}
+
+impl_precision!(LocalTime);
+impl_precision!(GlobalTime);
+impl_precision!(AnyTime);
+impl_precision!(ApproxLocalTime, LocalTime);
+impl_precision!(ApproxGlobalTime, GlobalTime);
+impl_precision!(ApproxAnyTime, AnyTime);
+
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
 
+    fn round_trip<T>(s: &str, expected: &str)
+    where
+        T: core::str::FromStr<Err = crate::Error>
+            + core::fmt::Display
+            + PartialEq
+            + core::fmt::Debug,
+    {
+        let time: T = s.parse().unwrap();
+        assert_eq!(time.to_string(), expected);
+        assert_eq!(time.to_string().parse::<T>().unwrap(), time);
+    }
+
+    #[test]
+    fn display() {
+        round_trip::<LocalTime<HmsTime>>("16:43:52", "16:43:52");
+        round_trip::<LocalTime<HmsTime>>("164352.1", "16:43:52.1");
+        round_trip::<LocalTime<HmsTime>>("16:43:52.100", "16:43:52.100");
+        round_trip::<LocalTime<HmsTime>>("16:43:52.0", "16:43:52.0");
+        round_trip::<LocalTime<HmsTime>>("T16:43:52.123456789", "16:43:52.123456789");
+        round_trip::<LocalTime<HmsTime>>("00:00:00.000001", "00:00:00.000001");
+        round_trip::<LocalTime<HmTime>>("16:43.5", "16:43.5");
+        round_trip::<LocalTime<HTime>>("16.25", "16.25");
+        round_trip::<GlobalTime<HmsTime>>("16:43:52Z", "16:43:52Z");
+        round_trip::<GlobalTime<HmsTime>>("16:43:52+00:00", "16:43:52+00:00");
+        round_trip::<GlobalTime<HmsTime>>("164352.2+0200", "16:43:52.2+02:00");
+        round_trip::<GlobalTime<HmsTime>>("16:43:52-00:30", "16:43:52-00:30");
+        round_trip::<GlobalTime<HmTime>>("16:43-05", "16:43-05:00");
+        round_trip::<GlobalTime<HTime>>("16+05:30", "16+05:30");
+        round_trip::<AnyTime<HmsTime>>("16:43:52", "16:43:52");
+        round_trip::<AnyTime<HmsTime>>("16:43:52Z", "16:43:52Z");
+        round_trip::<AnyTime<HmTime>>("16:43+01", "16:43+01:00");
+        round_trip::<AnyTime<HTime>>("16", "16");
+        round_trip::<ApproxLocalTime>("16:43", "16:43");
+        round_trip::<ApproxLocalTime>("16.5", "16.5");
+        round_trip::<ApproxGlobalTime>("16:43:52.5Z", "16:43:52.5Z");
+        round_trip::<ApproxGlobalTime>("16-08:00", "16-08:00");
+        round_trip::<ApproxAnyTime>("16:43:52", "16:43:52");
+        round_trip::<ApproxAnyTime>("16:43Z", "16:43Z");
+        round_trip::<ApproxAnyTime>("16", "16");
+        round_trip::<StrictTimezone>("16:43:52+05:45", "16:43:52+05:45");
+    }
+
+    #[test]
+    fn normalize_leap_second() {
+        let hms = |hour, minute, second| HmsTime {
+            hour,
+            minute,
+            second,
+        };
+
+        assert_eq!(
+            hms(13, 42, 59).normalize_leap_second(),
+            (hms(13, 42, 59), false)
+        );
+        assert_eq!(
+            hms(13, 42, 60).normalize_leap_second(),
+            (hms(13, 43, 0), true)
+        );
+        assert_eq!(
+            hms(13, 59, 60).normalize_leap_second(),
+            (hms(14, 0, 0), true)
+        );
+        assert_eq!(
+            hms(23, 59, 60).normalize_leap_second(),
+            (hms(0, 0, 0), true)
+        );
+
+        let ymd = |year, month, day| crate::YmdDate { year, month, day };
+        assert_eq!(
+            hms(23, 59, 60)
+                .normalize_with_date(ymd(2016, 12, 31))
+                .unwrap(),
+            (ymd(2017, 1, 1), hms(0, 0, 0), true)
+        );
+        assert_eq!(
+            hms(12, 59, 60)
+                .normalize_with_date(ymd(2016, 12, 31))
+                .unwrap(),
+            (ymd(2016, 12, 31), hms(13, 0, 0), true)
+        );
+        assert_eq!(
+            hms(23, 59, 59)
+                .normalize_with_date(ymd(2016, 12, 31))
+                .unwrap(),
+            (ymd(2016, 12, 31), hms(23, 59, 59), false)
+        );
+        assert!(hms(23, 59, 60)
+            .normalize_with_date(ymd(i32::MAX, 12, 31))
+            .is_err());
+    }
+
+    #[test]
+    fn new() {
+        assert!(HmsTime::new(23, 59, 60).is_ok());
+        assert!(HmsTime::new(24, 0, 0).is_ok());
+        assert!(HmsTime::new(24, 0, 1).is_err());
+        assert!(HmsTime::new(12, 60, 0).is_err());
+        assert!(HmTime::new(12, 30).is_ok());
+        assert!(HmTime::new(24, 30).is_err());
+        assert!(HTime::new(24).is_ok());
+        assert!(HTime::new(25).is_err());
+    }
+
+    #[test]
+    fn eq() {
+        fn assert_eq_impl<T: Eq>() {}
+        assert_eq_impl::<LocalTime<HmsTime>>();
+        assert_eq_impl::<LocalTime<HmTime>>();
+        assert_eq_impl::<LocalTime<HTime>>();
+        assert_eq_impl::<GlobalTime<HmsTime>>();
+        assert_eq_impl::<GlobalTime<HmTime>>();
+        assert_eq_impl::<GlobalTime<HTime>>();
+        assert_eq_impl::<AnyTime>();
+        assert_eq_impl::<ApproxAnyTime>();
+        assert_eq_impl::<crate::DateTime<crate::Date, ApproxAnyTime>>();
+
+        // the fraction is exact, so equality is reflexive and transitive
+        let a: LocalTime = "12:30:05.123456789".parse().unwrap();
+        let b: LocalTime = "12:30:05,1234567890".parse().unwrap();
+        let c = LocalTime {
+            naive: a.naive,
+            fraction: 123_456_789,
+            fraction_digits: 0,
+        };
+        assert_eq!(a, a);
+        assert!(a == b && b == c && a == c);
+        assert_ne!(a, "12:30:05.123456788".parse().unwrap());
+
+        let global: GlobalTime = "12:30:05.1+01:00".parse().unwrap();
+        assert_eq!(global, "T123005,100+01".parse().unwrap());
+        assert_ne!(global, "12:30:05.1+02:00".parse().unwrap());
+    }
+
+    #[test]
+    fn fraction_digits() {
+        for s in [
+            "12:30:05.250",
+            "12:30:05.000",
+            "12:30:05,5",
+            "12:30:05.000000001",
+            "12:30.50",
+            "12.0",
+        ] {
+            let time: ApproxAnyTime = s.parse().unwrap();
+            assert_eq!(
+                time.to_string(),
+                s.trim_start_matches('T').replace(',', ".")
+            );
+        }
+        assert_eq!(
+            "12:30:05.1234567890"
+                .parse::<LocalTime>()
+                .unwrap()
+                .to_string(),
+            "12:30:05.123456789"
+        );
+
+        let padded: LocalTime = "12:30:05.250".parse().unwrap();
+        let shortest: LocalTime = "12:30:05.25".parse().unwrap();
+        assert_eq!(padded.fraction_digits, 3);
+        assert_eq!(padded, shortest);
+        assert_eq!(padded.cmp(&shortest), core::cmp::Ordering::Equal);
+        assert_eq!(
+            LocalTime {
+                fraction: 125_000_000,
+                ..padded
+            }
+            .to_string(),
+            "12:30:05.125"
+        );
+        assert_eq!(
+            LocalTime {
+                fraction: 123_450_000,
+                ..padded
+            }
+            .to_string(),
+            "12:30:05.12345"
+        );
+    }
+
+    #[test]
+    fn timezone_from_str() {
+        for (s, timezone) in [
+            ("Z", Timezone::Utc),
+            ("+05:45", Timezone::Fixed(5 * 60 + 45)),
+            ("+0530", Timezone::Fixed(5 * 60 + 30)),
+            ("-05", Timezone::Fixed(-5 * 60)),
+            ("+00:00", Timezone::Fixed(0)),
+        ] {
+            assert_eq!(s.parse::<Timezone>().unwrap(), timezone);
+        }
+        assert_eq!(Timezone::Fixed(5 * 60 + 45).to_string(), "+05:45");
+        assert_eq!(Timezone::Utc.to_string(), "Z");
+        assert_eq!(
+            "+23:59".parse::<Timezone>().unwrap(),
+            Timezone::Fixed(23 * 60 + 59)
+        );
+        assert!(matches!(
+            "+24:00".parse::<Timezone>(),
+            Err(crate::Error::InvalidFormat { offset: Some(1) })
+        ));
+        assert!(matches!(
+            "+10:75".parse::<Timezone>(),
+            Err(crate::Error::InvalidFormat { offset: Some(4) })
+        ));
+        assert!(matches!(
+            "+1075".parse::<Timezone>(),
+            Err(crate::Error::InvalidFormat { offset: Some(3) })
+        ));
+        assert!("16:43:52+10:75".parse::<AnyTime>().is_err());
+        assert!("16:43:52+24:00".parse::<AnyTime>().is_err());
+        assert!(<AnyTime as crate::ParseStrict>::parse_strict("16:43:52+10:75").is_err());
+        assert!("16:43:52+23:59".parse::<AnyTime>().is_ok());
+        assert!(matches!(
+            "05:00".parse::<Timezone>(),
+            Err(crate::Error::InvalidFormat { offset: Some(0) })
+        ));
+    }
+
+    #[test]
+    fn validate_with() {
+        let rfc_3339 = ValidationPolicy::RFC_3339;
+        let default = ValidationPolicy::default();
+        for s in ["23:59:60Z", "24:00:00Z", "23:59:60.5+02:00"] {
+            let time: GlobalTime = s.parse().unwrap();
+            assert!(time.is_valid(), "{}", s);
+            assert!(time.validate_with(default), "{}", s);
+            assert!(!time.validate_with(rfc_3339), "{}", s);
+        }
+        let time: GlobalTime = "23:59:59.5Z".parse().unwrap();
+        assert!(time.validate_with(rfc_3339));
+        let datetime: crate::DateTime<crate::Date, GlobalTime> =
+            "2016-12-31T23:59:60Z".parse().unwrap();
+        assert!(!datetime.validate_with(rfc_3339));
+
+        let leap_second = ValidationPolicy {
+            allow_leap_second: true,
+            ..rfc_3339
+        };
+        assert!("23:59:60"
+            .parse::<LocalTime>()
+            .unwrap()
+            .validate_with(leap_second));
+        assert!(!"24"
+            .parse::<LocalTime<HTime>>()
+            .unwrap()
+            .validate_with(leap_second));
+        assert!(!LocalTime {
+            naive: HmsTime {
+                hour: 25,
+                minute: 0,
+                second: 0,
+            },
+            fraction: 0,
+            fraction_digits: 0,
+        }
+        .validate_with(default));
+    }
+
+    #[test]
+    fn precision() {
+        let time: ApproxGlobalTime = "13.5125+02:00".parse().unwrap();
+        assert!(matches!(time, ApproxGlobalTime::H(_)));
+        assert_eq!(time.into_hms().to_string(), "13:30:45+02:00");
+        assert_eq!(time.into_hm().to_string(), "13:30.75+02:00");
+        assert_eq!(time.into_h().to_string(), "13.5125+02:00");
+        assert_eq!(time.into_hms().timezone, Timezone::Fixed(120));
+
+        let time: ApproxGlobalTime = "13:42,5Z".parse().unwrap();
+        assert_eq!(time.into_hms().to_string(), "13:42:30Z");
+        // a billionth of an hour is 3.6 microseconds
+        assert_eq!(time.into_h().to_string(), "13.708333333Z");
+        assert_eq!(time.into_h().into_hms().to_string(), "13:42:29.9999988Z");
+
+        let time: ApproxAnyTime = "13.25".parse().unwrap();
+        assert_eq!(time.into_hms().to_string(), "13:15:00");
+        assert_eq!(time.into_hm().into_h().to_string(), "13.25");
+
+        let time: ApproxLocalTime = "13:42:05.5".parse().unwrap();
+        assert_eq!(time.into_hm().to_string(), "13:42.091666667");
+        // a billionth of a minute is 60 nanoseconds
+        assert_eq!(
+            time.into_hms().into_hm().into_hms().to_string(),
+            "13:42:05.50000002"
+        );
+
+        let time: AnyTime<HmTime> = "13:42.5Z".parse().unwrap();
+        assert_eq!(time.into_hms().to_string(), "13:42:30Z");
+        assert_eq!(time.into_hm(), time);
+    }
+
+    #[test]
+    fn to_utc() {
+        let utc = |s: &str| {
+            let (time, days) = s.parse::<GlobalTime>().unwrap().to_utc();
+            (time.to_string(), days)
+        };
+        assert_eq!(utc("16:43:52+02:00"), ("14:43:52Z".into(), 0));
+        assert_eq!(utc("16:43:52.5Z"), ("16:43:52.5Z".into(), 0));
+        assert_eq!(utc("01:30:00+02:00"), ("23:30:00Z".into(), -1));
+        assert_eq!(utc("22:15:60-05:30"), ("03:45:60Z".into(), 1));
+        assert_eq!(utc("24:00:00+00:00"), ("00:00:00Z".into(), 1));
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert("16:43:52.5Z".parse::<GlobalTime<HmsTime>>().unwrap()));
+        assert!(set.insert("16:43:52.5+02:00".parse::<GlobalTime<HmsTime>>().unwrap()));
+        assert!(!set.insert("T164352,5Z".parse::<GlobalTime<HmsTime>>().unwrap()));
+        // the number of fraction digits is ignored, like by equality
+        assert!(!set.insert("16:43:52.500Z".parse::<GlobalTime<HmsTime>>().unwrap()));
+        assert_eq!(set.len(), 2);
+
+        let mut set = HashSet::new();
+        for s in ["16:43Z", "16:43:00Z", "16:43", "16Z", "16:43:00.000Z"] {
+            set.insert(s.parse::<ApproxAnyTime>().unwrap());
+        }
+        // equal values have different accuracies, except for the last one
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            GlobalTime::<HmsTime>::try_from("16:43:52Z").unwrap(),
+            "16:43:52Z".parse::<GlobalTime>().unwrap()
+        );
+        assert!(matches!(
+            LocalTime::<HmsTime>::try_from("24:00:01"),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
     #[test]
     fn valid_time_hms() {
         assert!(HmsTime {
@@ -485,6 +2020,19 @@ mod tests {
             second: 61
         }
         .is_valid());
+
+        assert!(HmsTime {
+            hour: 24,
+            minute: 0,
+            second: 0
+        }
+        .is_valid());
+        assert!(!HmsTime {
+            hour: 24,
+            minute: 0,
+            second: 60
+        }
+        .is_valid());
     }
 
     #[test]
@@ -500,6 +2048,21 @@ mod tests {
             minute: 60
         }
         .is_valid());
+
+        assert!(HmTime {
+            hour: 24,
+            minute: 0
+        }
+        .is_valid());
+        assert!(!HmTime {
+            hour: 24,
+            minute: 30
+        }
+        .is_valid());
+        assert!(matches!(
+            "24:30".parse::<LocalTime<HmTime>>(),
+            Err(crate::Error::InvalidDate)
+        ));
     }
 
     #[test]
@@ -513,13 +2076,33 @@ mod tests {
     fn valid_time_local() {
         assert!(LocalTime {
             naive: HTime { hour: 0 },
-            fraction: 0.999
+            fraction: 999_000_000,
+            fraction_digits: 0,
         }
         .is_valid());
 
         assert!(!LocalTime {
             naive: HTime { hour: 0 },
-            fraction: 1.
+            fraction: 1_000_000_000,
+            fraction_digits: 0,
+        }
+        .is_valid());
+        assert!(LocalTime {
+            naive: HTime { hour: 24 },
+            fraction: 0,
+            fraction_digits: 0,
+        }
+        .is_valid());
+        assert!(!LocalTime {
+            naive: HTime { hour: 24 },
+            fraction: 500_000_000,
+            fraction_digits: 0,
+        }
+        .is_valid());
+        assert!(!LocalTime {
+            naive: HTime { hour: 0 },
+            fraction: u32::MAX,
+            fraction_digits: 0,
         }
         .is_valid());
     }
@@ -529,35 +2112,39 @@ mod tests {
         assert!(GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 0 },
-                fraction: 0.
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 24 * 60 - 1
+            timezone: Timezone::Fixed(24 * 60 - 1)
         }
         .is_valid());
 
         assert!(!GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 0 },
-                fraction: 0.
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 24 * 60
+            timezone: Timezone::Fixed(24 * 60)
         }
         .is_valid());
         assert!(!GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 0 },
-                fraction: 0.
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: -24 * 60
+            timezone: Timezone::Fixed(-24 * 60)
         }
         .is_valid());
 
         assert!(!GlobalTime {
             local: LocalTime {
                 naive: HTime { hour: 25 },
-                fraction: 0.
+                fraction: 0,
+                fraction_digits: 0,
             },
-            timezone: 0
+            timezone: Timezone::Utc
         }
         .is_valid());
     }
@@ -566,9 +2153,311 @@ mod tests {
     fn valid_time_any() {
         let local = LocalTime {
             naive: HTime { hour: 25 },
-            fraction: 0.,
+            fraction: 0,
+            fraction_digits: 0,
         };
         assert!(!AnyTime::Local(local.clone()).is_valid());
-        assert!(!AnyTime::Global(GlobalTime { local, timezone: 0 }).is_valid());
+        assert!(!AnyTime::Global(GlobalTime {
+            local,
+            timezone: Timezone::Utc
+        })
+        .is_valid());
+    }
+
+    fn print_time<T: Timelike>(t: &T) -> String {
+        format!(
+            "{:02}:{:02}:{:02}.{:09}",
+            t.hour(),
+            t.minute(),
+            t.second(),
+            t.nanosecond()
+        )
+    }
+
+    #[test]
+    fn timelike() {
+        let naive = HmsTime {
+            hour: 16,
+            minute: 43,
+            second: 52,
+        };
+        let local = LocalTime {
+            naive,
+            fraction: 500_000_000,
+            fraction_digits: 0,
+        };
+        let global = GlobalTime {
+            local,
+            timezone: Timezone::Fixed(120),
+        };
+
+        assert_eq!(print_time(&naive), "16:43:52.000000000");
+        assert_eq!(print_time(&local), "16:43:52.500000000");
+        assert_eq!(print_time(&global), "16:43:52.500000000");
+        assert_eq!(print_time(&AnyTime::Global(global)), "16:43:52.500000000");
+        assert_eq!(
+            print_time(&LocalTime {
+                naive: HmTime {
+                    hour: 16,
+                    minute: 43,
+                },
+                fraction: 500_000_000,
+                fraction_digits: 0,
+            }),
+            "16:43:30.000000000"
+        );
+        assert_eq!(
+            print_time(&ApproxAnyTime::H(AnyTime::Local(LocalTime {
+                naive: HTime { hour: 16 },
+                fraction: 250_000_000,
+                fraction_digits: 0,
+            }))),
+            "16:15:00.000000000"
+        );
+        assert_eq!(
+            print_time(&LocalTime {
+                naive: HTime { hour: 16 },
+                fraction: 100_000_000,
+                fraction_digits: 0,
+            }),
+            "16:06:00.000000000"
+        );
+        assert_eq!(
+            print_time(&LocalTime {
+                naive: HmTime {
+                    hour: 16,
+                    minute: 43,
+                },
+                fraction: 999_999_999,
+                fraction_digits: 0,
+            }),
+            "16:43:59.999999940"
+        );
+
+        assert_eq!(naive.offset_minutes(), None);
+        assert_eq!(local.offset_minutes(), None);
+        assert_eq!(global.offset_minutes(), Some(120));
+        assert_eq!(AnyTime::Global(global).offset_minutes(), Some(120));
+        assert_eq!(AnyTime::Local(local).offset_minutes(), None);
+        for (s, offset) in [
+            ("16:43Z", Some(0)),
+            ("16:43-00:00", Some(0)),
+            ("16-05:30", Some(-330)),
+            ("16:43:52", None),
+        ] {
+            assert_eq!(
+                s.parse::<ApproxAnyTime>().unwrap().offset_minutes(),
+                offset,
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            "16:43+01"
+                .parse::<ApproxGlobalTime>()
+                .unwrap()
+                .offset_minutes(),
+            Some(60)
+        );
+    }
+
+    #[test]
+    fn global_tuple() {
+        let naive = HmsTime {
+            hour: 16,
+            minute: 43,
+            second: 52,
+        };
+        let local = LocalTime {
+            naive,
+            fraction: 250_000_000,
+            fraction_digits: 0,
+        };
+
+        for timezone in [Timezone::Utc, Timezone::Fixed(330), Timezone::Fixed(-300)] {
+            let global = GlobalTime { local, timezone };
+
+            assert_eq!(<(HmsTime, Timezone)>::from(global), (naive, timezone));
+            assert_eq!(
+                GlobalTime::from((naive, timezone)),
+                GlobalTime {
+                    local: LocalTime {
+                        naive,
+                        fraction: 0,
+                        fraction_digits: 0
+                    },
+                    timezone
+                }
+            );
+
+            assert_eq!(<(LocalTime, Timezone)>::from(global), (local, timezone));
+            assert_eq!(GlobalTime::from((local, timezone)), global);
+        }
+    }
+
+    #[test]
+    fn partial_ord() {
+        let hms = |hour, minute, second| HmsTime {
+            hour,
+            minute,
+            second,
+        };
+        assert!(hms(9, 59, 59) < hms(10, 0, 0));
+        assert!(hms(10, 0, 0) < hms(10, 0, 1));
+        assert!(
+            HmTime {
+                hour: 9,
+                minute: 59
+            } < HmTime {
+                hour: 10,
+                minute: 0
+            }
+        );
+        assert!(HTime { hour: 9 } < HTime { hour: 10 });
+
+        let local = |s: &str| s.parse::<LocalTime<HmsTime>>().unwrap();
+        assert!(local("10:00:00.25") < local("10:00:00.5"));
+        assert!(local("10:00:00.5") < local("10:00:01"));
+
+        let global = |s: &str| s.parse::<GlobalTime<HmsTime>>().unwrap();
+        assert!(global("13:00:00+01:00") < global("12:30:00Z"));
+        assert!(global("12:00:00.5Z") > global("13:00:00+01:00"));
+        assert_eq!(
+            global("12:00:00Z").partial_cmp(&global("12:00:00Z")),
+            Some(core::cmp::Ordering::Equal)
+        );
+        // Same instant, ordered by timezone
+        assert!(global("11:00:00-01:00") < global("12:00:00Z"));
+        assert!(global("12:00:00Z") < global("13:00:00+01:00"));
+
+        let any = |s: &str| s.parse::<AnyTime<HmsTime>>().unwrap();
+        assert!(any("10:00:00") < any("11:00:00"));
+        assert!(any("10:00:00Z") < any("11:00:00Z"));
+        assert_eq!(any("10:00:00").partial_cmp(&any("11:00:00Z")), None);
+    }
+
+    #[test]
+    fn ord() {
+        let global = |s: &str| s.parse::<GlobalTime<HmsTime>>().unwrap();
+        let mut times = [
+            "13:00:00+01:00",
+            "12:30:00Z",
+            "12:00:00.5Z",
+            "12:00:00Z",
+            "11:00:00-01:00",
+            "12:00:00-00:00",
+            "00:30:00+02:00",
+        ]
+        .map(global);
+        times.sort();
+        assert_eq!(
+            times.map(|t| t.to_string()),
+            [
+                "00:30:00+02:00",
+                "11:00:00-01:00",
+                "12:00:00Z",
+                "12:00:00-00:00",
+                "13:00:00+01:00",
+                "12:00:00.5Z",
+                "12:30:00Z",
+            ]
+        );
+        for pair in times.windows(2) {
+            assert_eq!(pair[0].cmp(&pair[1]), core::cmp::Ordering::Less);
+            assert_eq!(
+                pair[0].partial_cmp(&pair[1]),
+                Some(core::cmp::Ordering::Less)
+            );
+        }
+        assert_eq!(
+            global("12:00:00.50Z").cmp(&global("12:00:00.5Z")),
+            core::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn same_instant() {
+        let global = |s: &str| s.parse::<GlobalTime<HmsTime>>().unwrap();
+        assert!(global("13:00:00+01:00").same_instant(&global("12:00:00Z")));
+        assert!(global("12:00:00-00:00").same_instant(&global("12:00:00Z")));
+        assert!(global("06:15:00-05:45").same_instant(&global("12:00:00Z")));
+        assert!(!global("12:00:00.5Z").same_instant(&global("12:00:00Z")));
+        assert!(!global("12:00:00+01:00").same_instant(&global("12:00:00Z")));
+
+        let hm: GlobalTime<HmTime> = "13:00.5+01:00".parse().unwrap();
+        assert!(hm.same_instant(&global("12:00:30Z")));
+        let h: GlobalTime<HTime> = "13+01".parse().unwrap();
+        assert!(h.same_instant(&global("12:00:00Z")));
+    }
+
+    #[test]
+    fn resolve() {
+        let resolve = |s: &str| {
+            let time = s.parse::<ApproxAnyTime>().unwrap();
+            (
+                time.resolve_start().to_string(),
+                time.resolve_end().to_string(),
+            )
+        };
+        let range = |start: &str, end: &str| (start.to_string(), end.to_string());
+
+        assert_eq!(resolve("13"), range("13:00:00", "13:59:59"));
+        assert_eq!(resolve("13:42"), range("13:42:00", "13:42:59"));
+        assert_eq!(resolve("13:42Z"), range("13:42:00Z", "13:42:59Z"));
+        assert_eq!(
+            resolve("13+02:00"),
+            range("13:00:00+02:00", "13:59:59+02:00")
+        );
+        assert_eq!(resolve("13:42:05"), range("13:42:05", "13:42:05"));
+        assert_eq!(resolve("13:42.5"), range("13:42:30", "13:42:30"));
+        assert_eq!(resolve("24"), range("24:00:00", "24:00:00"));
+    }
+
+    #[test]
+    fn timezone() {
+        let utc: GlobalTime = "16:43:52Z".parse().unwrap();
+        let fixed: GlobalTime = "16:43:52+00:00".parse().unwrap();
+        assert_eq!(utc.timezone, Timezone::Utc);
+        assert_eq!(fixed.timezone, Timezone::Fixed(0));
+        assert_ne!(utc, fixed);
+        assert_eq!(utc.timezone.minutes(), fixed.timezone.minutes());
+
+        assert!(Timezone::Fixed(-60) < Timezone::Utc);
+        assert!(Timezone::Utc < Timezone::Unknown);
+        assert!(Timezone::Unknown < Timezone::Fixed(0));
+        assert!(Timezone::Utc < Timezone::Fixed(0));
+
+        let unknown: GlobalTime = "16:43:52-00:00".parse().unwrap();
+        assert_eq!(unknown.timezone, Timezone::Unknown);
+        assert_eq!(unknown.timezone.minutes(), 0);
+        assert_eq!(unknown.to_string(), "16:43:52-00:00");
+        assert_ne!(unknown, fixed);
+        assert!(Timezone::Fixed(0) < Timezone::Fixed(60));
+    }
+
+    #[test]
+    fn strict_timezone() {
+        let local = LocalTime {
+            naive: HmsTime {
+                hour: 16,
+                minute: 43,
+                second: 52,
+            },
+            fraction: 0,
+            fraction_digits: 0,
+        };
+
+        assert!(StrictTimezone::new(GlobalTime {
+            local,
+            timezone: Timezone::Fixed(330)
+        })
+        .is_ok());
+        assert!(StrictTimezone::new(GlobalTime {
+            local,
+            timezone: Timezone::Fixed(331)
+        })
+        .is_err());
+        assert!("16:43:52-09:45".parse::<StrictTimezone>().is_ok());
+        assert!("16:43:52-09:46".parse::<StrictTimezone>().is_err());
     }
 }
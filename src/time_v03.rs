@@ -0,0 +1,451 @@
+#![cfg(feature = "time-v03")]
+use {
+    crate::{DateTime, GlobalTime, HmsTime, LocalTime, Timezone, YmdDate},
+    time_v03::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday},
+};
+
+/// Fails for invalid dates and years out of the range supported by `time`.
+impl TryFrom<YmdDate> for Date {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(date: YmdDate) -> Result<Self, Self::Error> {
+        let month = Month::try_from(date.month).or(Err(crate::Error::InvalidDate))?;
+        Date::from_calendar_date(date.year, month, date.day).or(Err(crate::Error::InvalidDate))
+    }
+}
+
+/// Week and ordinal dates go through their calendar date.
+impl TryFrom<crate::Date> for Date {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        crate::to_calendar_date(date)?.try_into()
+    }
+}
+
+/// Fails for leap seconds and hour 24, which `time` cannot represent.
+impl TryFrom<LocalTime<HmsTime>> for Time {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(time: LocalTime<HmsTime>) -> Result<Self, Self::Error> {
+        Time::from_hms_nano(
+            time.naive.hour,
+            time.naive.minute,
+            time.naive.second,
+            time.nanosecond(),
+        )
+        .or(Err(crate::Error::InvalidDate))
+    }
+}
+
+impl TryFrom<DateTime<crate::Date, LocalTime<HmsTime>>> for PrimitiveDateTime {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: DateTime<crate::Date, LocalTime<HmsTime>>) -> Result<Self, Self::Error> {
+        Ok(Date::try_from(dt.date)?.with_time(dt.time.try_into()?))
+    }
+}
+
+/// Fails for invalid dates, leap seconds
+/// and years out of the range supported by `time`.
+impl TryFrom<DateTime<YmdDate, GlobalTime<HmsTime>>> for OffsetDateTime {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: DateTime<YmdDate, GlobalTime<HmsTime>>) -> Result<Self, Self::Error> {
+        DateTime {
+            date: crate::Date::YMD(dt.date),
+            time: dt.time,
+        }
+        .try_into()
+    }
+}
+
+/// Fails like the conversion of a calendar date.
+impl TryFrom<DateTime<crate::Date, GlobalTime<HmsTime>>> for OffsetDateTime {
+    type Error = crate::Error;
+
+    fn try_from(dt: DateTime<crate::Date, GlobalTime<HmsTime>>) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::from_whole_seconds(dt.time.timezone.minutes() as i32 * 60)
+            .or(Err(crate::Error::InvalidDate))?;
+        let local = DateTime {
+            date: dt.date,
+            time: dt.time.local,
+        };
+
+        Ok(PrimitiveDateTime::try_from(local)?.assume_offset(offset))
+    }
+}
+
+/// Omitted date and time components are the first ones,
+/// like in [`ApproxDate::resolve_start`](crate::ApproxDate::resolve_start).
+impl TryFrom<DateTime<crate::ApproxDate, crate::ApproxGlobalTime>> for OffsetDateTime {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(
+        dt: DateTime<crate::ApproxDate, crate::ApproxGlobalTime>,
+    ) -> Result<Self, Self::Error> {
+        let date: crate::Date = dt.date.into();
+        let time: GlobalTime<HmsTime> = dt.time.into();
+        DateTime { date, time }.try_into()
+    }
+}
+
+impl From<Date> for YmdDate {
+    #[inline]
+    fn from(date: Date) -> Self {
+        YmdDate {
+            year: date.year(),
+            month: date.month().into(),
+            day: date.day(),
+        }
+    }
+}
+
+impl From<Date> for crate::Date {
+    #[inline]
+    fn from(date: Date) -> Self {
+        crate::Date::YMD(date.into())
+    }
+}
+
+impl From<Time> for LocalTime<HmsTime> {
+    #[inline]
+    fn from(time: Time) -> Self {
+        LocalTime {
+            naive: HmsTime {
+                hour: time.hour(),
+                minute: time.minute(),
+                second: time.second(),
+            },
+            fraction: time.nanosecond(),
+            fraction_digits: 0,
+        }
+    }
+}
+
+impl From<PrimitiveDateTime> for DateTime<crate::Date, LocalTime<HmsTime>> {
+    #[inline]
+    fn from(dt: PrimitiveDateTime) -> Self {
+        DateTime {
+            date: dt.date().into(),
+            time: dt.time().into(),
+        }
+    }
+}
+
+/// Fails for offsets that are not a whole number of minutes.
+impl TryFrom<OffsetDateTime> for DateTime<YmdDate, GlobalTime<HmsTime>> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+        let dt = DateTime::<crate::Date, GlobalTime<HmsTime>>::try_from(dt)?;
+        Ok(DateTime {
+            date: dt.as_ymd(),
+            time: dt.time,
+        })
+    }
+}
+
+/// Fails for offsets that are not a whole number of minutes.
+impl TryFrom<OffsetDateTime> for DateTime<crate::Date, GlobalTime<HmsTime>> {
+    type Error = crate::Error;
+
+    fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = dt.offset().whole_seconds();
+        if offset % 60 != 0 {
+            return Err(crate::Error::InvalidDate);
+        }
+
+        Ok(DateTime {
+            date: dt.date().into(),
+            time: GlobalTime {
+                local: dt.time().into(),
+                timezone: Timezone::Fixed((offset / 60) as i16),
+            },
+        })
+    }
+}
+
+impl From<crate::Weekday> for Weekday {
+    #[inline]
+    fn from(weekday: crate::Weekday) -> Self {
+        match weekday {
+            crate::Weekday::Monday => Weekday::Monday,
+            crate::Weekday::Tuesday => Weekday::Tuesday,
+            crate::Weekday::Wednesday => Weekday::Wednesday,
+            crate::Weekday::Thursday => Weekday::Thursday,
+            crate::Weekday::Friday => Weekday::Friday,
+            crate::Weekday::Saturday => Weekday::Saturday,
+            crate::Weekday::Sunday => Weekday::Sunday,
+        }
+    }
+}
+
+impl From<Weekday> for crate::Weekday {
+    #[inline]
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Monday => crate::Weekday::Monday,
+            Weekday::Tuesday => crate::Weekday::Tuesday,
+            Weekday::Wednesday => crate::Weekday::Wednesday,
+            Weekday::Thursday => crate::Weekday::Thursday,
+            Weekday::Friday => crate::Weekday::Friday,
+            Weekday::Saturday => crate::Weekday::Saturday,
+            Weekday::Sunday => crate::Weekday::Sunday,
+        }
+    }
+}
+
+#[cfg(feature = "time-v03-serde")]
+pub mod serde {
+    use super::OffsetDateTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Counterpart of [`deserialize_OffsetDateTime`], writing the datetime
+    /// in the extended format with its offset.
+    #[allow(non_snake_case)]
+    pub fn serialize_OffsetDateTime<S>(dt: &OffsetDateTime, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let dt = crate::DateTime::<crate::Date, crate::GlobalTime>::try_from(*dt)
+            .map_err(serde::ser::Error::custom)?;
+        ser.collect_str(&dt)
+    }
+
+    /// Parses an ISO-8601 datetime with a timezone, which may have reduced accuracy.
+    #[allow(non_snake_case)]
+    pub fn deserialize_OffsetDateTime<'de, D>(de: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(de)?
+            .parse::<crate::DateTime<crate::ApproxDate, crate::ApproxGlobalTime>>()
+            .map_err(serde::de::Error::custom)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_offset_datetime() {
+        let datetime: DateTime<YmdDate, GlobalTime<HmsTime>> = DateTime {
+            date: YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            },
+            time: "13:42:00.5+02:00".parse().unwrap(),
+        };
+        let expected = Date::from_calendar_date(2018, Month::August, 2)
+            .unwrap()
+            .with_hms_milli(13, 42, 0, 500)
+            .unwrap()
+            .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(OffsetDateTime::try_from(datetime).unwrap(), expected);
+
+        let leap_second = DateTime {
+            time: "23:59:60Z".parse().unwrap(),
+            ..datetime
+        };
+        let invalid_date = DateTime {
+            date: YmdDate {
+                year: 2018,
+                month: 2,
+                day: 30,
+            },
+            ..datetime
+        };
+        let invalid_month = DateTime {
+            date: YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1,
+            },
+            ..datetime
+        };
+        let out_of_range = DateTime {
+            date: YmdDate {
+                year: 10_000,
+                month: 1,
+                day: 1,
+            },
+            ..datetime
+        };
+        for datetime in [leap_second, invalid_date, invalid_month, out_of_range] {
+            assert!(matches!(
+                OffsetDateTime::try_from(datetime),
+                Err(crate::Error::InvalidDate)
+            ));
+        }
+    }
+
+    #[test]
+    fn from_offset_datetime() {
+        let datetime = Date::from_calendar_date(-44, Month::March, 15)
+            .unwrap()
+            .with_hms_milli(12, 30, 15, 250)
+            .unwrap()
+            .assume_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+        assert_eq!(
+            DateTime::<YmdDate, GlobalTime>::try_from(datetime).unwrap(),
+            DateTime {
+                date: YmdDate {
+                    year: -44,
+                    month: 3,
+                    day: 15,
+                },
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 12,
+                            minute: 30,
+                            second: 15,
+                        },
+                        fraction: 250_000_000,
+                        fraction_digits: 0,
+                    },
+                    timezone: Timezone::Fixed(-330),
+                },
+            }
+        );
+
+        let odd_offset = datetime.replace_offset(UtcOffset::from_hms(0, 0, 30).unwrap());
+        assert!(matches!(
+            DateTime::<crate::Date, GlobalTime>::try_from(odd_offset),
+            Err(crate::Error::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let datetime: DateTime<YmdDate, GlobalTime<HmsTime>> = DateTime {
+            date: YmdDate {
+                year: 1999,
+                month: 12,
+                day: 31,
+            },
+            time: "23:59:59.125-08:00".parse().unwrap(),
+        };
+        assert_eq!(
+            DateTime::<YmdDate, GlobalTime>::try_from(OffsetDateTime::try_from(datetime).unwrap())
+                .unwrap(),
+            datetime
+        );
+    }
+    #[test]
+    fn date_and_time() {
+        let expected = Date::from_calendar_date(2018, Month::January, 9).unwrap();
+        for s in ["2018-01-09", "2018-W02-2", "2018-009"] {
+            let date: crate::Date = s.parse().unwrap();
+            assert_eq!(Date::try_from(date).unwrap(), expected, "{}", s);
+        }
+        assert!(Date::try_from(crate::Date::O(crate::ODate {
+            year: 2018,
+            day: 366,
+        }))
+        .is_err());
+        assert_eq!(crate::Date::from(expected).to_string(), "2018-01-09");
+        assert_eq!(
+            crate::Weekday::from(expected.weekday()),
+            crate::Weekday::Tuesday
+        );
+        assert_eq!(Weekday::from(crate::Weekday::Tuesday), expected.weekday());
+
+        let time: LocalTime = "13:42:05.25".parse().unwrap();
+        let converted = Time::try_from(time).unwrap();
+        assert_eq!(converted, Time::from_hms_milli(13, 42, 5, 250).unwrap());
+        assert_eq!(LocalTime::from(converted), time);
+        for s in ["23:59:60", "24:00:00"] {
+            assert!(Time::try_from(s.parse::<LocalTime>().unwrap()).is_err());
+        }
+
+        let datetime: DateTime<crate::Date, LocalTime> = "2018-W02-2T13:42:05.25".parse().unwrap();
+        let primitive = PrimitiveDateTime::try_from(datetime).unwrap();
+        assert_eq!(primitive, PrimitiveDateTime::new(expected, converted));
+        assert_eq!(
+            DateTime::from(primitive).to_string(),
+            "2018-01-09T13:42:05.25"
+        );
+
+        let global: DateTime<crate::Date, GlobalTime> =
+            "2018-009T13:42:05.25+01:00".parse().unwrap();
+        let offset = OffsetDateTime::try_from(global).unwrap();
+        assert_eq!(
+            offset,
+            primitive.assume_offset(UtcOffset::from_hms(1, 0, 0).unwrap())
+        );
+        assert_eq!(
+            DateTime::<crate::Date, GlobalTime>::try_from(offset)
+                .unwrap()
+                .to_string(),
+            "2018-01-09T13:42:05.25+01:00"
+        );
+
+        let approx: DateTime<crate::ApproxDate, crate::ApproxGlobalTime> =
+            "2018-01T13Z".parse().unwrap();
+        assert_eq!(
+            OffsetDateTime::try_from(approx).unwrap(),
+            Date::from_calendar_date(2018, Month::January, 1)
+                .unwrap()
+                .with_hms(13, 0, 0)
+                .unwrap()
+                .assume_utc()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "time-v03-serde"))]
+mod serde_tests {
+    use serde::{Deserialize, Serialize};
+    use time_v03::{Date, Month, OffsetDateTime, UtcOffset};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Event {
+        #[serde(
+            serialize_with = "super::serde::serialize_OffsetDateTime",
+            deserialize_with = "super::serde::deserialize_OffsetDateTime"
+        )]
+        at: OffsetDateTime,
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"at":"2018-08-02T13:42:00.5+02:00"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            event.at,
+            Date::from_calendar_date(2018, Month::August, 2)
+                .unwrap()
+                .with_hms_milli(13, 42, 0, 500)
+                .unwrap()
+                .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap())
+        );
+        assert_eq!(serde_json::to_string(&event).unwrap(), json);
+
+        let seconds = Event {
+            at: event
+                .at
+                .replace_offset(UtcOffset::from_hms(0, 0, 30).unwrap()),
+        };
+        assert!(serde_json::to_string(&seconds).is_err());
+
+        let reduced: Event = serde_json::from_str(r#"{"at":"2018-W31-4T11Z"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&reduced).unwrap(),
+            r#"{"at":"2018-08-02T11:00:00+00:00"}"#
+        );
+        assert!(serde_json::from_str::<Event>(r#"{"at":"2018-08-02T11:42"}"#).is_err());
+    }
+}